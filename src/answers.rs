@@ -0,0 +1,232 @@
+use crate::runner::{Outcome, Report};
+use crate::{Answer, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The expected answers of the puzzles.
+///
+/// Stored as a text file with one answer per line, formatted as
+/// `<day> <part> <answer>`, e.g. `07 a 351901`. Newlines within an answer are
+/// written as `\n`, and lines starting with `#` are comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn new() -> Self {
+        Answers::default()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.expected.insert((day, part), answer.to_string());
+    }
+
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer.trim()),
+                _ => return Err(format!("line {}: expected `<day> <part> <answer>`", i + 1)),
+            };
+            let day = day
+                .parse::<u8>()
+                .map_err(|e| format!("line {}: invalid day `{}`: {}", i + 1, day, e))?;
+            let part = part
+                .parse::<Part>()
+                .map_err(|e| format!("line {}: {}", i + 1, e))?;
+            answers
+                .expected
+                .insert((day, part), answer.replace("\\n", "\n"));
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for ((day, part), answer) in self.expected.iter() {
+            writeln!(f, "{:02} {} {}", day, part, answer.replace('\n', "\\n"))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The solver found another answer than the expected one.
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The solver could not produce an answer.
+    Fail(String),
+    /// There is no expected answer to compare with.
+    Unknown(String),
+}
+
+/// The verdict of the verification of one solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    /// Returns true unless a solver failed or gave a wrong answer.
+    pub fn is_ok(&self) -> bool {
+        matches!(self.verdict, Verdict::Pass | Verdict::Unknown(_))
+    }
+}
+
+/// Compares the answers of the solvers with the expected ones.
+pub fn verify(reports: &[Report], answers: &Answers) -> Vec<Verification> {
+    reports
+        .iter()
+        .map(|report| Verification {
+            day: report.day,
+            part: report.part,
+            verdict: judge(&report.outcome, answers.get(report.day, report.part)),
+        })
+        .collect()
+}
+
+/// Compares the outcome of a solver with the expected answer, if known.
+pub fn judge(outcome: &Outcome, expected: Option<&str>) -> Verdict {
+    match (outcome, expected) {
+        (Outcome::Solved(run), Some(expected)) if run.answer.to_string() == expected => {
+            Verdict::Pass
+        }
+        (Outcome::Solved(run), Some(expected)) => Verdict::Mismatch {
+            expected: expected.to_string(),
+            actual: run.answer.to_string(),
+        },
+        (Outcome::Solved(run), None) => Verdict::Unknown(run.answer.to_string()),
+        (Outcome::MissingInput(e), _) => Verdict::Fail(format!("missing input: {}", e)),
+        (Outcome::Invalid(e), _) => Verdict::Fail(format!("invalid input: {}", e)),
+        (Outcome::Panicked(e), _) => Verdict::Fail(format!("panicked: {}", e)),
+    }
+}
+
+/// Counts the verdicts, e.g. `26 passed, 1 mismatched, 1 failed, 0 unknown`.
+pub fn summary<'a, I>(verdicts: I) -> String
+where
+    I: IntoIterator<Item = &'a Verdict>,
+{
+    let mut counts = [0; 4];
+    for verdict in verdicts {
+        counts[match verdict {
+            Verdict::Pass => 0,
+            Verdict::Mismatch { .. } => 1,
+            Verdict::Fail(_) => 2,
+            Verdict::Unknown(_) => 3,
+        }] += 1;
+    }
+    format!(
+        "{} passed, {} mismatched, {} failed, {} unknown",
+        counts[0], counts[1], counts[2], counts[3]
+    )
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let id = format!("day{:02}{}", self.day, self.part);
+        match &self.verdict {
+            Verdict::Pass => write!(f, "{}  pass", id),
+            Verdict::Mismatch { expected, actual } => write!(
+                f,
+                "{}  mismatch: expected `{}`, got `{}`",
+                id,
+                expected.replace('\n', "\\n"),
+                actual.replace('\n', "\\n")
+            ),
+            Verdict::Fail(e) => write!(f, "{}  fail: {}", id, e),
+            Verdict::Unknown(actual) => write!(
+                f,
+                "{}  unknown: no expected answer for `{}`",
+                id,
+                actual.replace('\n', "\\n")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::run_all;
+    use crate::{read_asset, registry};
+
+    const RAW_INPUT: &str = "# Known answers.
+01 a 1715
+13 b #..\\n.#.
+
+07 B 351901
+";
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = RAW_INPUT.parse::<Answers>().unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(1, Part::A), Some("1715"));
+        assert_eq!(answers.get(7, Part::B), Some("351901"));
+        assert_eq!(answers.get(13, Part::B), Some("#..\n.#."));
+        assert_eq!(answers.get(1, Part::B), None);
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        assert_eq!(
+            "01 a".parse::<Answers>().unwrap_err(),
+            "line 1: expected `<day> <part> <answer>`"
+        );
+        assert!("01 c 3".parse::<Answers>().is_err());
+        assert!("day01 a 3".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_verify_assets() {
+        let answers = Answers::load("assets/2021/answers.txt").unwrap();
+        let registry = registry(2021);
+        let reports = run_all(registry.iter(), |day| {
+            read_asset(2021, day).map_err(|e| io::Error::new(io::ErrorKind::NotFound, e))
+        });
+        for verification in verify(&reports, &answers) {
+            assert_eq!(verification.verdict, Verdict::Pass, "{}", verification);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::runner::{format_duration, run_solver, write_table, InputCache, Outcome};
+use crate::{stats, Part, Solver};
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+/// Statistics over the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Summary {
+    /// Summarizes the given durations, unless there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        let duration = |nanos: f64| Duration::from_nanos(nanos.round() as u64);
+        Some(Summary {
+            min: duration(stats::min(&nanos)?),
+            median: duration(stats::median(&nanos)?),
+            mean: duration(stats::mean(&nanos)?),
+            std_dev: duration(stats::std_dev(&nanos)?),
+        })
+    }
+}
+
+/// The timings of a solver over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub runs: usize,
+    pub parse: Summary,
+    pub solve: Summary,
+}
+
+/// The result of benchmarking one solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: Part,
+    /// The timings, or why the solver could not be benchmarked.
+    pub result: Result<Timings, String>,
+}
+
+/// Runs a solver `warmup` times, then `runs` times while timing it.
+///
+/// Stops at the first run which does not produce an answer.
+pub fn bench_solver(
+    solver: &dyn Solver,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Timings, String> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+    for i in 0..warmup + runs {
+        match run_solver(solver, input) {
+            Outcome::Solved(run) if i >= warmup => {
+                parse_times.push(run.parse_time);
+                solve_times.push(run.solve_time);
+            }
+            Outcome::Solved(_) => (),
+            outcome => return Err(outcome.to_string()),
+        }
+    }
+    match (
+        Summary::from_samples(&parse_times),
+        Summary::from_samples(&solve_times),
+    ) {
+        (Some(parse), Some(solve)) => Ok(Timings { runs, parse, solve }),
+        _ => Err("no run".to_string()),
+    }
+}
+
+/// Benchmarks the solvers one after the other.
+///
+/// The input of each day is only loaded once, by `read_input`.
+pub fn bench_all<'a, I, F>(solvers: I, read_input: F, warmup: usize, runs: usize) -> Vec<Benchmark>
+where
+    I: IntoIterator<Item = &'a dyn Solver>,
+    F: FnMut(u8) -> io::Result<String>,
+{
+    let mut inputs = InputCache::new(read_input);
+    solvers
+        .into_iter()
+        .map(|solver| Benchmark {
+            day: solver.day(),
+            part: solver.part(),
+            result: match inputs.get(solver.day()) {
+                Ok(input) => bench_solver(solver, input, warmup, runs),
+                Err(e) => Err(Outcome::MissingInput(e.to_string()).to_string()),
+            },
+        })
+        .collect()
+}
+
+/// Formats the benchmarks as a table, followed by the errors of the solvers
+/// which could not be benchmarked.
+pub struct BenchTable<'a>(pub &'a [Benchmark]);
+
+impl fmt::Display for BenchTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let header = [
+            "Day",
+            "Part",
+            "Runs",
+            "Parse min",
+            "median",
+            "mean",
+            "stddev",
+            "Solve min",
+            "median",
+            "mean",
+            "stddev",
+        ];
+        let rows = self
+            .0
+            .iter()
+            .map(|benchmark| {
+                let mut row = vec![format!("{:02}", benchmark.day), benchmark.part.to_string()];
+                match &benchmark.result {
+                    Ok(timings) => {
+                        row.push(timings.runs.to_string());
+                        for summary in [timings.parse, timings.solve] {
+                            row.extend(
+                                [summary.min, summary.median, summary.mean, summary.std_dev]
+                                    .map(format_duration),
+                            );
+                        }
+                    }
+                    Err(_) => row.extend(std::iter::repeat_n("-".to_string(), 9)),
+                }
+                row
+            })
+            .collect::<Vec<Vec<String>>>();
+        write_table(f, &header, &rows, 2)?;
+        for benchmark in self.0 {
+            if let Err(e) = &benchmark.result {
+                writeln!(f, "day{:02}{}: {}", benchmark.day, benchmark.part, e)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{answer, length};
+    use crate::{Registry, Solution};
+
+    #[test]
+    fn test_summary() {
+        let samples = [3, 1, 2, 6].map(Duration::from_micros);
+        let summary = Summary::from_samples(&samples).unwrap();
+        assert_eq!(summary.min, Duration::from_micros(1));
+        assert_eq!(summary.median, Duration::from_nanos(2500));
+        assert_eq!(summary.mean, Duration::from_micros(3));
+        assert_eq!(summary.std_dev, Duration::from_nanos(1871));
+        assert_eq!(Summary::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_all() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(1, Part::A, "Length", length, answer));
+        registry.register(Solution::new(2, Part::A, "Length", length, answer));
+        registry.register(Solution::new(3, Part::A, "Length", length, answer));
+        let benchmarks = bench_all(
+            registry.iter(),
+            |day| match day {
+                1 => Ok("abc".to_string()),
+                2 => Ok("".to_string()),
+                _ => Err(io::Error::new(io::ErrorKind::NotFound, "not found")),
+            },
+            1,
+            5,
+        );
+
+        assert_eq!(benchmarks.len(), 3);
+        assert_eq!(benchmarks[0].result.as_ref().map(|t| t.runs), Ok(5));
+        assert_eq!(
+            benchmarks[1].result,
+            Err("invalid input: day 02: empty input".to_string())
+        );
+        assert_eq!(
+            benchmarks[2].result,
+            Err("missing input: not found".to_string())
+        );
+
+        let table = BenchTable(&benchmarks).to_string();
+        assert!(table.starts_with("Day  Part  Runs  Parse min"));
+        assert_eq!(table.lines().count(), 6);
+        assert!(table.ends_with("day03a: missing input: not found\n"));
+    }
+}
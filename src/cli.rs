@@ -0,0 +1,806 @@
+use crate::config::{Config, DEFAULT_CONFIG};
+use crate::generate::DEFAULT_SEED;
+use crate::history::DEFAULT_HISTORY;
+use crate::journal::{Entry, DEFAULT_JOURNAL};
+use crate::log::Level;
+use crate::repl::DEFAULT_REPL_HISTORY;
+use crate::server::{Limits, DEFAULT_ADDRESS};
+use crate::submit::DEFAULT_COOLDOWN;
+use crate::{asset_path_in, assets_dir, year_dir, Part, DEFAULT_YEAR, YEARS};
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage: aoc2021 [OPTIONS] <COMMAND>
+
+Commands:
+  list                 List the solved puzzles
+  all                  Solve every puzzle and print a timing table
+  dayNN                Solve both parts of a day, e.g. `day01`
+  dayNNx               Solve a puzzle, e.g. `day01a`
+  verify               Check every answer against the expected answers
+  examples             Check every puzzle against the examples of `examples/YYYY/dayNN/`
+  bench [TARGET]       Time repeated runs of every puzzle, or of TARGET (`dayNN` or `dayNNx`)
+  new <dayNN>          Generate the module, input and example files of a new day
+  fetch <dayNN>        Download the input of a day into the assets directory, unless already there
+  submit <dayNN> <a|b> Solve a puzzle and submit its answer, unless the journal rejects it
+  journal <dayNN> [<a|b> <ANSWER> <high|low|wrong>]
+                       List the rejected answers of a day, or record one
+  serve                Serve the solvers over HTTP: `GET /solvers`, `POST /day/NN/part/a` with the input
+  repl                 Explore the inputs interactively: run the solvers, tweak and print intermediate results
+  generate <dayNN>     Print a random input of a day, the same for the same seed
+
+Options:
+  -y, --year <YYYY>    Year of the puzzles [default: 2021]
+  -i, --input <PATH>   Read the puzzle input from PATH, or from stdin if PATH is `-`
+  --assets-dir <DIR>   Directory holding the puzzle inputs of each year in <DIR>/<YYYY>, their journals, the submit cooldown and the repl history [env: AOC_ASSETS_DIR] [default: assets]
+  --answers <PATH>     File holding the expected answers [default: <DIR>/<YYYY>/answers.txt]
+  -j, --jobs <N>       Number of puzzles `all`, `dayNN` and `verify` solve concurrently [default: 1]
+  --runs <N>           Number of timed runs of `bench` [default: 10]
+  --warmup <N>         Number of untimed runs of `bench` before timing [default: 1]
+  --history <PATH>     File `bench` appends its results to [default: bench_history.tsv]
+  --compare            Make `bench` flag the solvers slower than in their last recorded run
+  --threshold <PCT>    Slowdown beyond which `--compare` flags a regression [default: 10]
+  --config <PATH>      File holding the session token and base URL [default: aoc2021.conf]
+  --base-url <URL>     URL of the Advent of Code website [env: AOC_BASE_URL] [default: https://adventofcode.com]
+  --force              Make `submit` ignore the warnings of the journal
+  --listen <ADDR>      Address `serve` listens on [default: 127.0.0.1:2021]
+  --max-body <BYTES>   Largest input `serve` accepts [default: 1048576]
+  --timeout <SECS>     Time `serve` gives each request to be received and solved [default: 10]
+  --max-connections <N>
+                       Number of requests `serve` handles at once, others being refused [default: 32]
+  --seed <N>           Seed of the random input of `generate` [default: 2021]
+  --size <N>           Size of the random input of `generate`, e.g. the number of lines [default: per day]
+  -v                   Log what the solvers do to stderr, more with `-vv` and `-vvv`
+  --log-level <LEVEL>  Log at `off`, `warn`, `info`, `debug` or `trace` level [default: warn]
+  -h, --help           Print this message";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    All,
+    Day(u8),
+    Solve(String),
+    Verify,
+    Examples,
+    Bench(Target),
+    /// Scaffold the files of a new day.
+    New(u8),
+    /// Download the input of a day.
+    Fetch(u8),
+    /// Solve a puzzle and submit its answer.
+    Submit(u8, Part),
+    /// List the rejected answers of a day, or record one.
+    Journal(u8, Option<Entry>),
+    /// Serve the solvers over HTTP.
+    Serve,
+    /// Start an interactive shell.
+    Repl,
+    /// Print a random input of a day.
+    Generate(u8),
+}
+
+/// The puzzles a command applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Day(u8),
+    Puzzle(String),
+}
+
+impl Target {
+    fn parse(arg: &str) -> Self {
+        match arg {
+            "all" => Target::All,
+            _ => match parse_day(arg) {
+                Some(day) => Target::Day(day),
+                None => Target::Puzzle(arg.to_string()),
+            },
+        }
+    }
+}
+
+pub const DEFAULT_RUNS: usize = 10;
+pub const DEFAULT_WARMUP: usize = 1;
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// The `dayNN.txt` file of the assets directory.
+    Assets,
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub year: u16,
+    pub input: Input,
+    /// The directory holding the assets directory of each year.
+    pub assets_dir: PathBuf,
+    pub answers: Option<PathBuf>,
+    /// Number of solvers to run concurrently.
+    pub jobs: usize,
+    /// Number of timed runs of each benchmarked solver.
+    pub runs: usize,
+    /// Number of untimed runs of each benchmarked solver.
+    pub warmup: usize,
+    pub history: Option<PathBuf>,
+    /// Whether to compare the benchmarks with the history.
+    pub compare: bool,
+    /// Slowdown, in percent, beyond which a benchmark regressed.
+    pub threshold: u32,
+    pub config: Option<PathBuf>,
+    pub base_url: Option<String>,
+    /// Whether to submit answers the journal warns about.
+    pub force: bool,
+    pub listen: String,
+    pub limits: Limits,
+    /// The most verbose messages logged, if any.
+    pub log_level: Option<Level>,
+    pub seed: u64,
+    /// The size of the generated input, if not the default of the day.
+    pub size: Option<usize>,
+}
+
+impl Options {
+    /// Parses the command line arguments, without the program name.
+    pub fn parse<I, S>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut year = DEFAULT_YEAR;
+        let mut input = Input::Assets;
+        let mut assets = None;
+        let mut answers = None;
+        let mut jobs = None;
+        let mut runs = None;
+        let mut warmup = None;
+        let mut history = None;
+        let mut compare = false;
+        let mut threshold = None;
+        let mut config = None;
+        let mut base_url = None;
+        let mut force = false;
+        let mut listen = None;
+        let mut max_body = None;
+        let mut timeout = None;
+        let mut max_connections = None;
+        let mut verbosity = 0;
+        let mut log_level = None;
+        let mut seed = None;
+        let mut size = None;
+        let mut positionals: Vec<String> = Vec::new();
+
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            // Support both `--option value` and `--option=value`.
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => {
+                    (name.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or(format!("missing value for `{}`", name))
+            };
+            match name.as_str() {
+                "-h" | "--help" => return Ok(Options::with_command(Command::Help)),
+                "-y" | "--year" => year = parse_year(&value()?)?,
+                "-i" | "--input" => {
+                    input = match value()?.as_str() {
+                        "-" => Input::Stdin,
+                        path => Input::File(PathBuf::from(path)),
+                    }
+                }
+                "--assets-dir" => assets = Some(PathBuf::from(value()?)),
+                "--answers" => answers = Some(PathBuf::from(value()?)),
+                "-j" | "--jobs" => jobs = Some(parse_count(&name, &value()?, 1)?),
+                "--runs" => runs = Some(parse_count(&name, &value()?, 1)?),
+                "--warmup" => warmup = Some(parse_count(&name, &value()?, 0)?),
+                "--history" => history = Some(PathBuf::from(value()?)),
+                "--compare" => compare = true,
+                "--threshold" => {
+                    let percent = parse_count(&name, &value()?, 0)?;
+                    threshold = Some(u32::try_from(percent).map_err(|_| {
+                        format!(
+                            "invalid value `{}` for `{}`: expected a number up to {}",
+                            percent,
+                            name,
+                            u32::MAX
+                        )
+                    })?)
+                }
+                "--config" => config = Some(PathBuf::from(value()?)),
+                "--base-url" => base_url = Some(value()?),
+                "--force" => force = true,
+                "--listen" => listen = Some(value()?),
+                "--max-body" => max_body = Some(parse_count(&name, &value()?, 1)?),
+                "--timeout" => timeout = Some(parse_count(&name, &value()?, 1)? as u64),
+                "--max-connections" => max_connections = Some(parse_count(&name, &value()?, 1)?),
+                "--seed" => {
+                    let value = value()?;
+                    seed = Some(value.parse::<u64>().map_err(|_| {
+                        format!("invalid value `{}` for `--seed`: expected a number", value)
+                    })?)
+                }
+                "--size" => size = Some(parse_count(&name, &value()?, 1)?),
+                flags
+                    if flags.len() > 1
+                        && flags.starts_with('-')
+                        && flags[1..].chars().all(|c| c == 'v') =>
+                {
+                    verbosity += flags.len() - 1
+                }
+                "--log-level" => {
+                    log_level = match value()?.as_str() {
+                        "off" => Some(None),
+                        level => Some(Some(level.parse()?)),
+                    }
+                }
+                option if option.starts_with('-') && option.len() > 1 => {
+                    return Err(format!("unknown option `{}`", option))
+                }
+                _ => positionals.push(arg),
+            }
+        }
+
+        let command = match positionals.as_slice() {
+            [] => Command::Help,
+            [command] if command == "list" => Command::List,
+            [command] if command == "all" => Command::All,
+            [command] if command == "verify" => Command::Verify,
+            [command] if command == "examples" => Command::Examples,
+            [command] if command == "bench" => Command::Bench(Target::All),
+            [command, target] if command == "bench" => Command::Bench(Target::parse(target)),
+            [command] if command == "new" => return Err("missing the day to create".into()),
+            [command, day] if command == "new" => Command::New(parse_puzzle_day(day)?),
+            [command] if command == "fetch" => return Err("missing the day to fetch".into()),
+            [command, day] if command == "fetch" => Command::Fetch(parse_puzzle_day(day)?),
+            [command, ..] if command == "submit" && positionals.len() < 3 => {
+                return Err("missing the day and part to submit".into())
+            }
+            [command, day, part] if command == "submit" => {
+                Command::Submit(parse_puzzle_day(day)?, part.parse()?)
+            }
+            [command] if command == "serve" => Command::Serve,
+            [command] if command == "repl" => Command::Repl,
+            [command] if command == "generate" => return Err("missing the day to generate".into()),
+            [command, day] if command == "generate" => Command::Generate(parse_puzzle_day(day)?),
+            [command] if command == "journal" => {
+                return Err("missing the day of the journal".into())
+            }
+            [command, day] if command == "journal" => {
+                Command::Journal(parse_puzzle_day(day)?, None)
+            }
+            [command, day, part, answer, hint] if command == "journal" => Command::Journal(
+                parse_puzzle_day(day)?,
+                Some(Entry {
+                    part: part.parse()?,
+                    answer: answer.clone(),
+                    hint: hint.parse()?,
+                }),
+            ),
+            [command, ..] if command == "journal" => {
+                return Err("expected `journal <dayNN> [<a|b> <ANSWER> <high|low|wrong>]`".into())
+            }
+            [problem] => match parse_day(problem) {
+                Some(day) => Command::Day(day),
+                None => Command::Solve(problem.clone()),
+            },
+            [_, extra, ..] => return Err(format!("unexpected argument `{}`", extra)),
+        };
+        let every_day = matches!(
+            command,
+            Command::All | Command::Verify | Command::Examples | Command::Bench(Target::All)
+        );
+        if every_day && input != Input::Assets {
+            return Err("`--input` cannot be used to solve every day".into());
+        }
+        let without_input = matches!(
+            command,
+            Command::New(_) | Command::Fetch(_) | Command::Repl | Command::Generate(_)
+        );
+        if without_input && input != Input::Assets {
+            return Err(
+                "`--input` cannot be used with `new`, `fetch`, `repl` and `generate`".into(),
+            );
+        }
+        let online = matches!(command, Command::Fetch(_) | Command::Submit(..));
+        if !online && (config.is_some() || base_url.is_some()) {
+            return Err(
+                "`--config` and `--base-url` can only be used with `fetch` and `submit`".into(),
+            );
+        }
+        if !matches!(command, Command::Submit(..)) && force {
+            return Err("`--force` can only be used with `submit`".into());
+        }
+        let serve_options = listen.is_some()
+            || max_body.is_some()
+            || timeout.is_some()
+            || max_connections.is_some();
+        if command != Command::Serve && serve_options {
+            return Err("`--listen`, `--max-body`, `--timeout` and `--max-connections` can only be used with `serve`".into());
+        }
+        if !matches!(command, Command::Generate(_)) && (seed.is_some() || size.is_some()) {
+            return Err("`--seed` and `--size` can only be used with `generate`".into());
+        }
+        let concurrent = matches!(command, Command::All | Command::Day(_) | Command::Verify);
+        if !concurrent && jobs.is_some() {
+            return Err("`--jobs` can only be used with `all`, `dayNN` and `verify`".into());
+        }
+        let log_level = match log_level {
+            Some(_) if verbosity > 0 => {
+                return Err("`-v` and `--log-level` cannot be used together".into())
+            }
+            Some(level) => level,
+            None => Some(Level::from_verbosity(verbosity)),
+        };
+        let bench_options = runs.is_some()
+            || warmup.is_some()
+            || history.is_some()
+            || compare
+            || threshold.is_some();
+        if !matches!(command, Command::Bench(_)) && bench_options {
+            return Err(
+                "`--runs`, `--warmup`, `--history`, `--compare` and `--threshold` \
+                 can only be used with `bench`"
+                    .into(),
+            );
+        }
+        Ok(Options {
+            command,
+            year,
+            input,
+            assets_dir: assets.unwrap_or_else(assets_dir),
+            answers,
+            jobs: jobs.unwrap_or(1),
+            runs: runs.unwrap_or(DEFAULT_RUNS),
+            warmup: warmup.unwrap_or(DEFAULT_WARMUP),
+            history,
+            compare,
+            threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+            config,
+            base_url,
+            force,
+            listen: listen.unwrap_or_else(|| DEFAULT_ADDRESS.to_string()),
+            limits: Limits {
+                max_body: max_body.unwrap_or(Limits::default().max_body),
+                timeout: timeout.map_or(Limits::default().timeout, Duration::from_secs),
+                max_connections: max_connections.unwrap_or(Limits::default().max_connections),
+            },
+            log_level,
+            seed: seed.unwrap_or(DEFAULT_SEED),
+            size,
+        })
+    }
+
+    fn with_command(command: Command) -> Self {
+        Options {
+            command,
+            year: DEFAULT_YEAR,
+            input: Input::Assets,
+            assets_dir: assets_dir(),
+            answers: None,
+            jobs: 1,
+            runs: DEFAULT_RUNS,
+            warmup: DEFAULT_WARMUP,
+            history: None,
+            compare: false,
+            threshold: DEFAULT_THRESHOLD,
+            config: None,
+            base_url: None,
+            force: false,
+            listen: DEFAULT_ADDRESS.to_string(),
+            limits: Limits::default(),
+            log_level: Some(Level::Warn),
+            seed: DEFAULT_SEED,
+            size: None,
+        }
+    }
+
+    /// Returns the assets directory of the year.
+    pub fn year_assets_dir(&self) -> PathBuf {
+        year_dir(&self.assets_dir, self.year)
+    }
+
+    /// Returns the path of the expected answers file.
+    pub fn answers_path(&self) -> PathBuf {
+        self.answers
+            .clone()
+            .unwrap_or_else(|| self.year_assets_dir().join("answers.txt"))
+    }
+
+    /// Returns the directory of the journals of the year.
+    pub fn journal_dir(&self) -> PathBuf {
+        self.year_assets_dir().join(DEFAULT_JOURNAL)
+    }
+
+    /// Returns the path of the file holding the submit cooldown.
+    pub fn cooldown_path(&self) -> PathBuf {
+        self.assets_dir.join(DEFAULT_COOLDOWN)
+    }
+
+    /// Returns the path of the file the repl saves its commands to.
+    pub fn repl_history_path(&self) -> PathBuf {
+        self.assets_dir.join(DEFAULT_REPL_HISTORY)
+    }
+
+    /// Returns the path of the benchmark history file.
+    pub fn history_path(&self) -> PathBuf {
+        self.history
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY))
+    }
+
+    /// Loads the configuration to talk to the Advent of Code website.
+    ///
+    /// `--base-url` takes precedence over the environment, which takes
+    /// precedence over the configuration file.
+    pub fn load_config(&self) -> io::Result<Config> {
+        let path = self
+            .config
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG));
+        let mut config = Config::load(&path, |key| env::var(key).ok())?;
+        if let Some(base_url) = &self.base_url {
+            config.base_url = base_url.clone();
+        }
+        Ok(config)
+    }
+
+    /// Reads the puzzle input of a given day.
+    pub fn read_input(&self, day: u8) -> io::Result<String> {
+        match &self.input {
+            Input::Assets => fs::read_to_string(asset_path_in(self.year_assets_dir(), day)),
+            Input::File(path) => fs::read_to_string(path),
+            Input::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                Ok(buffer)
+            }
+        }
+    }
+}
+
+/// Parses a day identifier like `day01`.
+fn parse_day(arg: &str) -> Option<u8> {
+    let digits = arg.strip_prefix("day")?;
+    if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Parses the day of a puzzle, from `day01` to `day25`.
+fn parse_puzzle_day(arg: &str) -> Result<u8, String> {
+    match parse_day(arg) {
+        Some(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day `{}`: expected `day01` to `day25`",
+            arg
+        )),
+    }
+}
+
+/// Parses a year hosted by the crate.
+fn parse_year(arg: &str) -> Result<u16, String> {
+    match arg.parse::<u16>() {
+        Ok(year) if crate::year(year).is_some() => Ok(year),
+        _ => Err(format!(
+            "invalid year `{}`: expected one of {}",
+            arg,
+            YEARS
+                .iter()
+                .map(|y| y.year.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Parses the value of a counting option, which must be at least `min`.
+fn parse_count(name: &str, value: &str, min: usize) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(count) if count >= min => Ok(count),
+        _ => Err(format!(
+            "invalid value `{}` for `{}`: expected a number greater than or equal to {}",
+            value, name, min
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::journal::Hint;
+
+    #[test]
+    fn test_parse_solve() {
+        let options = Options::parse(["day01a"]).unwrap();
+        assert_eq!(options.command, Command::Solve("day01a".into()));
+        assert_eq!(options.input, Input::Assets);
+    }
+
+    #[test]
+    fn test_parse_all_and_day() {
+        assert_eq!(Options::parse(["all"]).unwrap().command, Command::All);
+        assert_eq!(Options::parse(["day07"]).unwrap().command, Command::Day(7));
+        assert_eq!(
+            Options::parse(["day7b"]).unwrap().command,
+            Command::Solve("day7b".into())
+        );
+        assert!(Options::parse(["all", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let options = Options::parse(["--input", "-", "day01a"]).unwrap();
+        assert_eq!(options.input, Input::Stdin);
+        let options = Options::parse(["day01a", "-i", "mine.txt"]).unwrap();
+        assert_eq!(options.input, Input::File("mine.txt".into()));
+        let options = Options::parse(["--input=mine.txt", "day01a"]).unwrap();
+        assert_eq!(options.input, Input::File("mine.txt".into()));
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(Options::parse(["list"]).unwrap().year, DEFAULT_YEAR);
+        assert_eq!(
+            Options::parse(["--year", "2021", "list"]).unwrap().year,
+            2021
+        );
+        assert_eq!(Options::parse(["-y", "2021", "day01a"]).unwrap().year, 2021);
+        assert_eq!(
+            Options::parse(["--year=1999", "list"]).unwrap_err(),
+            "invalid year `1999`: expected one of 2021"
+        );
+        assert!(Options::parse(["-y", "twenty", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_log_level() {
+        assert_eq!(
+            Options::parse(["day14a"]).unwrap().log_level,
+            Some(Level::Warn)
+        );
+        assert_eq!(
+            Options::parse(["-v", "day14a"]).unwrap().log_level,
+            Some(Level::Info)
+        );
+        assert_eq!(
+            Options::parse(["day14a", "-vv"]).unwrap().log_level,
+            Some(Level::Debug)
+        );
+        assert_eq!(
+            Options::parse(["-v", "-vv", "day14a"]).unwrap().log_level,
+            Some(Level::Trace)
+        );
+        assert_eq!(
+            Options::parse(["--log-level", "trace", "day14a"])
+                .unwrap()
+                .log_level,
+            Some(Level::Trace)
+        );
+        assert_eq!(
+            Options::parse(["--log-level=off", "day14a"])
+                .unwrap()
+                .log_level,
+            None
+        );
+        assert!(Options::parse(["--log-level", "loud", "day14a"]).is_err());
+        assert!(Options::parse(["-v", "--log-level", "info", "day14a"]).is_err());
+        assert!(Options::parse(["-vx", "day14a"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_assets_dir() {
+        let options = Options::parse(["--assets-dir", "/tmp/aoc", "list"]).unwrap();
+        assert_eq!(options.command, Command::List);
+        assert_eq!(options.assets_dir, PathBuf::from("/tmp/aoc"));
+        assert_eq!(options.year_assets_dir(), PathBuf::from("/tmp/aoc/2021"));
+        assert_eq!(
+            options.answers_path(),
+            PathBuf::from("/tmp/aoc/2021/answers.txt")
+        );
+        assert_eq!(
+            options.journal_dir(),
+            PathBuf::from("/tmp/aoc/2021/journal")
+        );
+        assert_eq!(
+            options.cooldown_path(),
+            PathBuf::from("/tmp/aoc/.aoc2021_cooldown")
+        );
+        assert_eq!(
+            options.repl_history_path(),
+            PathBuf::from("/tmp/aoc/.aoc2021_repl_history")
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let options = Options::parse(["verify", "--answers", "mine.txt"]).unwrap();
+        assert_eq!(options.command, Command::Verify);
+        assert_eq!(options.answers_path(), PathBuf::from("mine.txt"));
+        assert!(Options::parse(["verify", "-i", "mine.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let options = Options::parse(["examples"]).unwrap();
+        assert_eq!(options.command, Command::Examples);
+        assert!(Options::parse(["examples", "-i", "-"]).is_err());
+        assert!(Options::parse(["examples", "-j", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(Options::parse(["all"]).unwrap().jobs, 1);
+        assert_eq!(Options::parse(["all", "--jobs", "4"]).unwrap().jobs, 4);
+        assert_eq!(Options::parse(["-j", "8", "verify"]).unwrap().jobs, 8);
+        assert_eq!(Options::parse(["day06", "-j", "2"]).unwrap().jobs, 2);
+        assert!(Options::parse(["all", "--jobs", "0"]).is_err());
+        assert!(Options::parse(["bench", "--jobs", "2"]).is_err());
+        assert!(Options::parse(["day06a", "--jobs", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let options = Options::parse(["bench"]).unwrap();
+        assert_eq!(options.command, Command::Bench(Target::All));
+        assert_eq!(
+            (options.runs, options.warmup),
+            (DEFAULT_RUNS, DEFAULT_WARMUP)
+        );
+        let options = Options::parse(["bench", "day06", "--runs", "50", "--warmup=0"]).unwrap();
+        assert_eq!(options.command, Command::Bench(Target::Day(6)));
+        assert_eq!((options.runs, options.warmup), (50, 0));
+        let options = Options::parse(["bench", "day14b", "-i", "-"]).unwrap();
+        assert_eq!(
+            options.command,
+            Command::Bench(Target::Puzzle("day14b".into()))
+        );
+        assert!(Options::parse(["bench", "--runs", "0"]).is_err());
+        assert!(Options::parse(["bench", "-i", "-"]).is_err());
+        assert!(Options::parse(["all", "--runs", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_history() {
+        let options = Options::parse(["bench"]).unwrap();
+        assert!(!options.compare);
+        assert_eq!(options.threshold, DEFAULT_THRESHOLD);
+        assert_eq!(options.history_path(), PathBuf::from(DEFAULT_HISTORY));
+        let options = Options::parse([
+            "bench",
+            "--compare",
+            "--threshold",
+            "25",
+            "--history",
+            "mine.tsv",
+        ])
+        .unwrap();
+        assert!(options.compare);
+        assert_eq!(options.threshold, 25);
+        assert_eq!(options.history_path(), PathBuf::from("mine.tsv"));
+        assert!(Options::parse(["verify", "--compare"]).is_err());
+        let error = Options::parse(["bench", "--threshold", "4294967296"]).unwrap_err();
+        assert_eq!(
+            error,
+            "invalid value `4294967296` for `--threshold`: expected a number up to 4294967295"
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        let options = Options::parse(["new", "day15"]).unwrap();
+        assert_eq!(options.command, Command::New(15));
+        assert!(Options::parse(["new"]).is_err());
+        assert!(Options::parse(["new", "day26"]).is_err());
+        assert!(Options::parse(["new", "day15", "-i", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let options =
+            Options::parse(["fetch", "day06", "--base-url", "http://localhost:8080"]).unwrap();
+        assert_eq!(options.command, Command::Fetch(6));
+        assert_eq!(options.base_url.as_deref(), Some("http://localhost:8080"));
+        let config = options.load_config().unwrap();
+        assert_eq!(
+            config.url(2021, "day/6/input"),
+            "http://localhost:8080/2021/day/6/input"
+        );
+        assert!(Options::parse(["fetch"]).is_err());
+        assert!(Options::parse(["fetch", "day0"]).is_err());
+        assert!(Options::parse(["day06", "--config", "aoc.conf"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let options = Options::parse(["submit", "day09", "a", "-i", "-"]).unwrap();
+        assert_eq!(options.command, Command::Submit(9, Part::A));
+        assert_eq!(options.input, Input::Stdin);
+        assert!(Options::parse(["submit", "day09"]).is_err());
+        assert!(Options::parse(["submit", "day09", "c"]).is_err());
+        assert!(Options::parse(["submit", "day09", "a", "b"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_journal() {
+        let options = Options::parse(["journal", "day09"]).unwrap();
+        assert_eq!(options.command, Command::Journal(9, None));
+        let options = Options::parse(["journal", "day09", "a", "530", "low"]).unwrap();
+        let entry = Entry {
+            part: Part::A,
+            answer: "530".to_string(),
+            hint: Hint::TooLow,
+        };
+        assert_eq!(options.command, Command::Journal(9, Some(entry)));
+        assert!(Options::parse(["journal", "day09", "a", "530"]).is_err());
+        assert!(Options::parse(["journal", "day09", "a", "530", "big"]).is_err());
+        assert!(
+            Options::parse(["submit", "day09", "a", "--force"])
+                .unwrap()
+                .force
+        );
+        assert!(Options::parse(["day09", "--force"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        let options = Options::parse(["serve"]).unwrap();
+        assert_eq!(options.command, Command::Serve);
+        assert_eq!(options.listen, DEFAULT_ADDRESS);
+        assert_eq!(options.limits, Limits::default());
+        let options =
+            Options::parse(["serve", "--listen", "0.0.0.0:80", "--timeout", "2"]).unwrap();
+        assert_eq!(options.listen, "0.0.0.0:80");
+        assert_eq!(options.limits.timeout, Duration::from_secs(2));
+        assert!(Options::parse(["serve", "--max-body", "0"]).is_err());
+        let options = Options::parse(["serve", "--max-connections", "4"]).unwrap();
+        assert_eq!(options.limits.max_connections, 4);
+        assert!(Options::parse(["all", "--max-connections", "4"]).is_err());
+        assert!(Options::parse(["all", "--timeout", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_repl() {
+        let options = Options::parse(["repl", "--assets-dir", "inputs"]).unwrap();
+        assert_eq!(options.command, Command::Repl);
+        assert_eq!(options.assets_dir, PathBuf::from("inputs"));
+        assert!(Options::parse(["repl", "-i", "-"]).is_err());
+        assert!(Options::parse(["repl", "day06"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let options = Options::parse(["generate", "day06"]).unwrap();
+        assert_eq!(options.command, Command::Generate(6));
+        assert_eq!((options.seed, options.size), (DEFAULT_SEED, None));
+        let options = Options::parse(["generate", "day06", "--seed", "7", "--size=50"]).unwrap();
+        assert_eq!((options.seed, options.size), (7, Some(50)));
+        assert!(Options::parse(["generate"]).is_err());
+        assert!(Options::parse(["generate", "day06", "--size", "0"]).is_err());
+        assert!(Options::parse(["generate", "day06", "--seed", "-1"]).is_err());
+        assert!(Options::parse(["generate", "day06", "-i", "-"]).is_err());
+        assert!(Options::parse(["day06a", "--seed", "7"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Options::parse(["day01a", "--input"]).is_err());
+        assert!(Options::parse(["--verbose", "day01a"]).is_err());
+        assert!(Options::parse(["day01a", "day01b"]).is_err());
+        assert_eq!(Options::parse(["-h"]).unwrap().command, Command::Help);
+    }
+}
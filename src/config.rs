@@ -0,0 +1,145 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The default configuration file, relative to the current directory.
+pub const DEFAULT_CONFIG: &str = "aoc2021.conf";
+
+/// Environment variable overriding the session token of the configuration.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable overriding the base URL of the configuration.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The settings to talk to the Advent of Code website.
+///
+/// Stored as a text file of `key = value` lines, where lines starting with
+/// `#` are comments:
+///
+/// ```text
+/// session = 53616c746564...
+/// base_url = https://adventofcode.com
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration file, if it exists, then applies the overrides
+    /// of the environment variables, as looked up by `env`.
+    pub fn load<P, F>(path: P, env: F) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut config = match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(e),
+        };
+        if let Some(session) = env(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env(BASE_URL_ENV) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    /// Returns the session cookie, or an error explaining how to set it.
+    pub fn cookie(&self) -> io::Result<String> {
+        match self.session.as_deref() {
+            Some(session) if !session.is_empty() => Ok(format!("session={}", session)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "no session token: set `session` in {} or the {} environment variable",
+                    DEFAULT_CONFIG, SESSION_ENV
+                ),
+            )),
+        }
+    }
+
+    /// Returns the URL of a page of the event of a year, e.g. `day/6/input`.
+    pub fn url(&self, year: u16, page: &str) -> String {
+        format!("{}/{}/{}", self.base_url.trim_end_matches('/'), year, page)
+    }
+}
+
+impl FromStr for Config {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `<key> = <value>`", i + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                key => return Err(format!("line {}: unknown key `{}`", i + 1, key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = "# Secret.\nsession = abc123\n".parse::<Config>().unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.cookie().unwrap(), "session=abc123");
+        assert_eq!(
+            config.url(2021, "day/6/input"),
+            "https://adventofcode.com/2021/day/6/input"
+        );
+        assert!(Config::default().cookie().is_err());
+        assert_eq!(
+            "session abc".parse::<Config>().unwrap_err(),
+            "line 1: expected `<key> = <value>`"
+        );
+        assert!("token = abc".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_load_config() {
+        let env = |key: &str| match key {
+            BASE_URL_ENV => Some("http://127.0.0.1:8080/".to_string()),
+            _ => None,
+        };
+        let config = Config::load("does/not/exist.conf", env).unwrap();
+        assert_eq!(config.session, None);
+        assert_eq!(
+            config.url(2015, "day/1"),
+            "http://127.0.0.1:8080/2015/day/1"
+        );
+    }
+}
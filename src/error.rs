@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Error raised while loading or parsing a puzzle input.
+///
+/// Besides the message, it records where the error happened: the day, the
+/// line and field numbers (starting at 1) and the offending text, when known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    day: Option<u8>,
+    line: Option<usize>,
+    field: Option<usize>,
+    text: Option<String>,
+    message: String,
+}
+
+impl AocError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        AocError {
+            day: None,
+            line: None,
+            field: None,
+            text: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error about an offending piece of text.
+    pub fn invalid<M: Into<String>>(text: &str, message: M) -> Self {
+        AocError {
+            text: Some(text.to_string()),
+            ..AocError::new(message)
+        }
+    }
+
+    /// Sets the day, unless it is already known.
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Sets the line number, unless it is already known.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the field number, unless it is already known.
+    pub fn with_field(mut self, field: usize) -> Self {
+        self.field.get_or_insert(field);
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn field(&self) -> Option<usize> {
+        self.field
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {:02}", day)),
+            self.line.map(|line| format!("line {}", line)),
+            self.field.map(|field| format!("field {}", field)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, ": `{}`", text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {}
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> Self {
+        AocError::new(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = AocError::invalid("sideways 5", "unknown direction");
+        assert_eq!(error.to_string(), "unknown direction: `sideways 5`");
+        let error = error.with_line(3).with_day(2).with_line(4);
+        assert_eq!(error.line(), Some(3));
+        assert_eq!(
+            error.to_string(),
+            "day 02, line 3: unknown direction: `sideways 5`"
+        );
+        assert_eq!(AocError::new("empty input").to_string(), "empty input");
+        let error = AocError::invalid("x", "invalid number").with_field(2);
+        assert_eq!(error.to_string(), "field 2: invalid number: `x`");
+        assert_eq!(
+            error.with_line(1).to_string(),
+            "line 1, field 2: invalid number: `x`"
+        );
+    }
+}
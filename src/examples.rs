@@ -0,0 +1,189 @@
+use crate::answers::{judge, Verdict, Verification};
+use crate::runner::run_solver;
+use crate::{Part, Registry};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The default directory holding the examples, one directory per year.
+pub const DEFAULT_EXAMPLES: &str = "examples";
+
+/// An example input of a puzzle, with the answers it should give.
+///
+/// Examples are stored as `examples/YYYY/dayNN/<name>.txt`, next to an optional
+/// `<name>.answers` file with one expected answer per line, formatted as
+/// `<part> <answer>`, e.g. `a 7`. Newlines within an answer are written as
+/// `\n`, and lines starting with `#` are comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub expected: BTreeMap<Part, String>,
+}
+
+impl Example {
+    /// Loads an example and its expected answers.
+    pub fn load<P: AsRef<Path>>(day: u8, path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let input = fs::read_to_string(path)?;
+        let expected = match fs::read_to_string(path.with_extension("answers")) {
+            Ok(content) => parse_expected(&content).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.with_extension("answers").display(), e),
+                )
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Example {
+            day,
+            name,
+            input,
+            expected,
+        })
+    }
+
+    /// Returns the parts to check the example with: those with an expected
+    /// answer, or both when none is known yet.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.expected.is_empty() {
+            true => Part::all().to_vec(),
+            false => self.expected.keys().copied().collect(),
+        }
+    }
+}
+
+fn parse_expected(s: &str) -> Result<BTreeMap<Part, String>, String> {
+    let mut expected = BTreeMap::new();
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (part, answer) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| format!("line {}: expected `<part> <answer>`", i + 1))?;
+        let part = part
+            .parse::<Part>()
+            .map_err(|e| format!("line {}: {}", i + 1, e))?;
+        expected.insert(part, answer.trim().replace("\\n", "\n"));
+    }
+    Ok(expected)
+}
+
+/// Loads the examples of every day from the directory of a year, sorted by
+/// day and name.
+///
+/// Empty example files, as created by `new`, are skipped.
+pub fn load_examples<P: AsRef<Path>>(dir: P) -> io::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    for day_dir in sorted_entries(dir.as_ref())? {
+        let day = match day_number(&day_dir) {
+            Some(day) if day_dir.is_dir() => day,
+            _ => continue,
+        };
+        for path in sorted_entries(&day_dir)? {
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                let example = Example::load(day, &path)?;
+                if !example.input.trim().is_empty() {
+                    examples.push(example);
+                }
+            }
+        }
+    }
+    Ok(examples)
+}
+
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn day_number(path: &Path) -> Option<u8> {
+    let digits = path.file_name()?.to_str()?.strip_prefix("day")?;
+    match digits.len() == 2 {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// The verdict of a solver on an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleCheck {
+    pub name: String,
+    pub verification: Verification,
+}
+
+/// Runs the registered solvers against the examples of their day.
+///
+/// An example without a solver for one of its parts fails, so that a missing
+/// solver does not go unnoticed.
+pub fn check_examples(registry: &Registry, examples: &[Example]) -> Vec<ExampleCheck> {
+    let mut checks = Vec::new();
+    for example in examples {
+        for part in example.parts() {
+            let expected = example.expected.get(&part).map(String::as_str);
+            let verdict = match registry.get(example.day, part) {
+                Some(solver) => judge(&run_solver(solver, &example.input), expected),
+                None if example.expected.is_empty() => continue,
+                None => Verdict::Fail("no solver".to_string()),
+            };
+            checks.push(ExampleCheck {
+                name: example.name.clone(),
+                verification: Verification {
+                    day: example.day,
+                    part,
+                    verdict,
+                },
+            });
+        }
+    }
+    checks
+}
+
+impl fmt::Display for ExampleCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}  ({})", self.verification, self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{registry, year_dir};
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = parse_expected("# Sample.\na 17\nB #..\\n.#.\n").unwrap();
+        assert_eq!(expected.get(&Part::A).map(String::as_str), Some("17"));
+        assert_eq!(expected.get(&Part::B).map(String::as_str), Some("#..\n.#."));
+        assert_eq!(
+            parse_expected("a 1\n17").unwrap_err(),
+            "line 2: expected `<part> <answer>`"
+        );
+        assert!(parse_expected("c 17").is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = load_examples(year_dir(DEFAULT_EXAMPLES, 2021)).unwrap();
+        let registry = registry(2021);
+        for day in 1..=14 {
+            assert!(examples.iter().any(|e| e.day == day), "day {}", day);
+        }
+        for check in check_examples(&registry, &examples) {
+            assert_eq!(check.verification.verdict, Verdict::Pass, "{}", check);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::config::Config;
+use crate::{asset_path_in, http};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already in the assets directory.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of a day of `year` into `assets_dir`, the assets
+/// directory of that year.
+///
+/// An input which is already there is never downloaded again, unless it is
+/// empty like the placeholders of `new`.
+pub fn fetch_input<P: AsRef<Path>>(
+    config: &Config,
+    assets_dir: P,
+    year: u16,
+    day: u8,
+) -> io::Result<Fetched> {
+    let path = asset_path_in(&assets_dir, day);
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+    let response = http::get(
+        &config.url(year, &format!("day/{}/input", day)),
+        &config.cookie()?,
+    )?;
+    if !response.is_success() {
+        return Err(io::Error::other(format!(
+            "the server answered {}: {}",
+            response.status,
+            response.body.lines().next().unwrap_or_default()
+        )));
+    }
+    if response.body.trim().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the server sent an empty input",
+        ));
+    }
+    fs::create_dir_all(&assets_dir)?;
+    // Write to a temporary file first, so an interrupted download does not
+    // leave a truncated input behind.
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, &response.body)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::mock;
+
+    #[test]
+    fn test_fetch_input() {
+        let assets = std::env::temp_dir().join(format!("aoc2021_fetch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&assets);
+        let (base_url, server) = mock::serve(vec![
+            (200, "3,4,3,1,2\n".to_string()),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".into(),
+            ),
+        ]);
+        let config = Config {
+            session: Some("abc123".to_string()),
+            base_url,
+        };
+
+        let path = asset_path_in(&assets, 6);
+        assert_eq!(
+            fetch_input(&config, &assets, 2021, 6).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3,4,3,1,2\n");
+        assert_eq!(
+            fetch_input(&config, &assets, 2021, 6).unwrap(),
+            Fetched::Cached(path)
+        );
+        let error = fetch_input(&config, &assets, 2021, 25).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the server answered 404: Please don't repeatedly request this endpoint before it unlocks!"
+        );
+        assert!(!asset_path_in(&assets, 25).exists());
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2021/day/6/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        fs::remove_dir_all(&assets).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_without_session() {
+        let config = Config::default();
+        let assets = std::env::temp_dir().join("aoc2021_fetch_no_session");
+        assert_eq!(
+            fetch_input(&config, &assets, 2021, 3).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Parsing and solving functions shared by the tests of the modules which run
+//! solvers, to register them as `Solution`s.
+
+use crate::{Answer, AocError};
+use std::thread;
+use std::time::Duration;
+
+/// Parses the input as its length, refusing an empty input.
+pub fn length(input: &str) -> Result<usize, AocError> {
+    match input {
+        "" => Err(AocError::new("empty input")),
+        _ => Ok(input.len()),
+    }
+}
+
+/// Parses the input as a number.
+pub fn number(input: &str) -> Result<u64, AocError> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| AocError::invalid(input, "expected a number"))
+}
+
+/// Answers what was parsed.
+pub fn answer<T: Into<Answer>>(value: T) -> Answer {
+    value.into()
+}
+
+pub fn forty_two<T>(_value: T) -> Answer {
+    Answer::from(42)
+}
+
+pub fn double(n: u64) -> Answer {
+    (n * 2).into()
+}
+
+/// Sleeps for the parsed number of milliseconds.
+pub fn sleep(millis: u64) -> Answer {
+    thread::sleep(Duration::from_millis(millis));
+    "done".into()
+}
+
+pub fn boom<T>(_value: T) -> Answer {
+    panic!("boom")
+}
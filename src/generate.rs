@@ -0,0 +1,153 @@
+//! Random puzzle inputs, to stress the solvers beyond the examples and our
+//! own inputs.
+//!
+//! Inputs are generated from a seed, so that an input which breaks a solver
+//! can be generated again.
+
+use std::ops::RangeInclusive;
+
+/// The seed used unless another one is given.
+pub const DEFAULT_SEED: u64 = 2021;
+
+/// A small pseudo-random number generator (SplitMix64).
+///
+/// Not suitable for cryptography, but fast, and the same seed always gives
+/// the same numbers on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Returns a number in the range.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        let span = end.abs_diff(start) as u128 + 1;
+        start.wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    /// Returns `true` once in `n` times on average.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles the items in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates valid inputs of a day.
+pub struct Generator {
+    pub day: u8,
+    /// What the size counts and its default value, e.g. `("depths", 2000)`.
+    pub size: (&'static str, usize),
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// Collection of the generators, in registration order.
+#[derive(Default)]
+pub struct Generators {
+    generators: Vec<Generator>,
+}
+
+impl Generators {
+    pub fn new() -> Self {
+        Generators::default()
+    }
+
+    pub fn register(&mut self, generator: Generator) {
+        self.generators.push(generator);
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Generator> {
+        self.generators.iter().find(|g| g.day == day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Generator> {
+        self.generators.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::{run_solver, Outcome};
+    use crate::{generators, registry, Part};
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        // The first output of SplitMix64 seeded with 0.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.range(-2..=2)));
+        }
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+        let mut items = (0..20).collect::<Vec<u32>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<u32>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_generators() {
+        let registry = registry(2021);
+        let generators = generators(2021);
+        assert_eq!(generators.iter().count(), 14);
+        for generator in generators.iter() {
+            let (_, default_size) = generator.size;
+            for seed in 0..3 {
+                let size = default_size.min(20);
+                let input = (generator.generate)(&mut Rng::new(seed), size);
+                assert_eq!(input, (generator.generate)(&mut Rng::new(seed), size));
+                for part in Part::all() {
+                    let solver = registry.get(generator.day, part).unwrap();
+                    let outcome = run_solver(solver, &input);
+                    assert!(
+                        matches!(outcome, Outcome::Solved(_)),
+                        "{} with seed {}: {}\n{}",
+                        solver.id(),
+                        seed,
+                        outcome,
+                        input
+                    );
+                }
+            }
+        }
+    }
+}
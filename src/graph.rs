@@ -0,0 +1,346 @@
+use std::collections::VecDeque;
+
+/// Identifies a node of a `Graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Identifies an edge of a `Graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(usize);
+
+impl EdgeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Edge<E> {
+    from: NodeId,
+    to: NodeId,
+    weight: E,
+}
+
+/// A directed graph whose nodes are labelled with an `N` and whose edges carry
+/// an `E`.
+///
+/// Nodes and edges are stored in arenas and referred to by their ids, so the
+/// graph can be freely traversed while being borrowed. Undirected graphs are
+/// made of pairs of edges, see `add_undirected_edge`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    edges: Vec<Edge<E>>,
+    /// The outgoing edges of each node.
+    adjacency: Vec<Vec<EdgeId>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        self.nodes.push(node);
+        self.adjacency.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Adds an edge going from `from` to `to`.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: E) -> EdgeId {
+        assert!(
+            from.0 < self.nodes.len() && to.0 < self.nodes.len(),
+            "unknown node"
+        );
+        let id = EdgeId(self.edges.len());
+        self.edges.push(Edge { from, to, weight });
+        self.adjacency[from.0].push(id);
+        id
+    }
+
+    /// Adds an edge in both directions between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, weight: E) -> (EdgeId, EdgeId)
+    where
+        E: Clone,
+    {
+        (
+            self.add_edge(a, b, weight.clone()),
+            self.add_edge(b, a, weight),
+        )
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> &mut N {
+        &mut self.nodes[id.0]
+    }
+
+    /// Returns the endpoints and the weight of an edge.
+    pub fn edge(&self, id: EdgeId) -> (NodeId, NodeId, &E) {
+        let edge = &self.edges[id.0];
+        (edge.from, edge.to, &edge.weight)
+    }
+
+    /// Finds the first edge going from `from` to `to`.
+    pub fn find_edge(&self, from: NodeId, to: NodeId) -> Option<EdgeId> {
+        self.adjacency[from.0]
+            .iter()
+            .copied()
+            .find(|id| self.edges[id.0].to == to)
+    }
+
+    /// Finds the first node matching `predicate`.
+    pub fn find<P>(&self, predicate: P) -> Option<NodeId>
+    where
+        P: FnMut(&N) -> bool,
+    {
+        self.nodes.iter().position(predicate).map(NodeId)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate().map(|(i, n)| (NodeId(i), n))
+    }
+
+    /// Iterates over the nodes reachable from `id` through one edge.
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.adjacency[id.0].iter().map(move |e| self.edges[e.0].to)
+    }
+
+    /// Iterates over the outgoing edges of `id`, with their destination.
+    pub fn edges_from(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.adjacency[id.0].iter().map(move |e| {
+            let edge = &self.edges[e.0];
+            (edge.to, &edge.weight)
+        })
+    }
+
+    pub fn degree(&self, id: NodeId) -> usize {
+        self.adjacency[id.0].len()
+    }
+
+    /// Visits the nodes reachable from `start`, depth first.
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, N, E> {
+        Dfs {
+            graph: self,
+            stack: vec![start],
+            visited: vec![false; self.nodes.len()],
+        }
+    }
+
+    /// Visits the nodes reachable from `start`, breadth first.
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, N, E> {
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start.0] = true;
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([start]),
+            visited,
+        }
+    }
+
+    /// Calls `on_path` with every path going from `start` to `end`.
+    ///
+    /// A path only moves on to a node when `can_enter(path, node)` allows it,
+    /// `path` being the nodes visited so far, `start` included. This hook is
+    /// what keeps the enumeration finite: without it, any cycle would be
+    /// walked forever. Paths stop as soon as they reach `end`. The walk keeps
+    /// its own stack, so long paths do not overflow the call stack.
+    pub fn for_each_path<F, P>(&self, start: NodeId, end: NodeId, mut can_enter: F, mut on_path: P)
+    where
+        F: FnMut(&[NodeId], NodeId) -> bool,
+        P: FnMut(&[NodeId]),
+    {
+        let mut path = vec![start];
+        if start == end {
+            on_path(&path);
+            return;
+        }
+        // The neighbors left to try at each node of the path.
+        let mut pending = vec![self.neighbors(start)];
+        while let Some(neighbors) = pending.last_mut() {
+            match neighbors.next() {
+                Some(next) if can_enter(&path, next) => {
+                    path.push(next);
+                    if next == end {
+                        on_path(&path);
+                        path.pop();
+                    } else {
+                        pending.push(self.neighbors(next));
+                    }
+                }
+                Some(_) => {}
+                None => {
+                    pending.pop();
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Counts the paths going from `start` to `end`, see `for_each_path`.
+    pub fn count_paths<F>(&self, start: NodeId, end: NodeId, can_enter: F) -> usize
+    where
+        F: FnMut(&[NodeId], NodeId) -> bool,
+    {
+        let mut count = 0;
+        self.for_each_path(start, end, can_enter, |_| count += 1);
+        count
+    }
+}
+
+/// Depth first iterator over the nodes of a graph, see `Graph::dfs`.
+pub struct Dfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    stack: Vec<NodeId>,
+    visited: Vec<bool>,
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        while let Some(id) = self.stack.pop() {
+            if self.visited[id.0] {
+                continue;
+            }
+            self.visited[id.0] = true;
+            // Push the neighbors in reverse, so they are visited in order.
+            let neighbors = self.graph.adjacency[id.0].iter().rev();
+            self.stack.extend(
+                neighbors
+                    .map(|e| self.graph.edges[e.0].to)
+                    .filter(|n| !self.visited[n.0]),
+            );
+            return Some(id);
+        }
+        None
+    }
+}
+
+/// Breadth first iterator over the nodes of a graph, see `Graph::bfs`.
+pub struct Bfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    queue: VecDeque<NodeId>,
+    visited: Vec<bool>,
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.queue.pop_front()?;
+        for next in self.graph.neighbors(id) {
+            if !self.visited[next.0] {
+                self.visited[next.0] = true;
+                self.queue.push_back(next);
+            }
+        }
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// a - b - d
+    ///  \ /
+    ///   c     e
+    fn sample() -> (Graph<&'static str>, Vec<NodeId>) {
+        let mut graph = Graph::new();
+        let ids = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|&label| graph.add_node(label))
+            .collect::<Vec<NodeId>>();
+        graph.add_undirected_edge(ids[0], ids[1], ());
+        graph.add_undirected_edge(ids[0], ids[2], ());
+        graph.add_undirected_edge(ids[1], ids[2], ());
+        graph.add_undirected_edge(ids[1], ids[3], ());
+        (graph, ids)
+    }
+
+    fn labels(graph: &Graph<&'static str>, ids: impl Iterator<Item = NodeId>) -> String {
+        ids.map(|id| *graph.node(id)).collect()
+    }
+
+    #[test]
+    fn test_graph_adjacency() {
+        let (graph, ids) = sample();
+        assert_eq!((graph.len(), graph.edge_count()), (5, 8));
+        assert_eq!(graph.find(|&n| n == "c"), Some(ids[2]));
+        assert_eq!(graph.find(|&n| n == "z"), None);
+        assert_eq!(labels(&graph, graph.neighbors(ids[1])), "acd");
+        assert_eq!(graph.degree(ids[4]), 0);
+        assert!(graph.find_edge(ids[1], ids[3]).is_some());
+        assert!(graph.find_edge(ids[0], ids[3]).is_none());
+    }
+
+    #[test]
+    fn test_graph_traversals() {
+        let (graph, ids) = sample();
+        assert_eq!(labels(&graph, graph.dfs(ids[0])), "abcd");
+        assert_eq!(labels(&graph, graph.bfs(ids[0])), "abcd");
+        assert_eq!(labels(&graph, graph.bfs(ids[3])), "dbac");
+        assert_eq!(labels(&graph, graph.dfs(ids[4])), "e");
+    }
+
+    #[test]
+    fn test_graph_paths() {
+        let (graph, ids) = sample();
+        let mut paths = Vec::new();
+        graph.for_each_path(
+            ids[0],
+            ids[3],
+            |path, next| !path.contains(&next),
+            |path| paths.push(labels(&graph, path.iter().copied())),
+        );
+        assert_eq!(paths, vec!["abd", "acbd"]);
+        let simple = |path: &[NodeId], next| !path.contains(&next);
+        assert_eq!(graph.count_paths(ids[2], ids[3], simple), 2);
+        assert_eq!(graph.count_paths(ids[0], ids[4], simple), 0);
+        assert_eq!(graph.count_paths(ids[4], ids[4], simple), 1);
+
+        // Deeper than the call stack would allow.
+        let mut chain: Graph<usize> = Graph::new();
+        let ids = (0..100_000)
+            .map(|i| chain.add_node(i))
+            .collect::<Vec<NodeId>>();
+        for pair in ids.windows(2) {
+            chain.add_undirected_edge(pair[0], pair[1], ());
+        }
+        let forward =
+            |path: &[NodeId], next: NodeId| path.len() < 2 || path[path.len() - 2] != next;
+        assert_eq!(chain.count_paths(ids[0], ids[99_999], forward), 1);
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{parse_lines, AocError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Cells are addressed either by their `(x, y)` coordinates, `x` being the
+/// column and `y` the row, or by their index in the underlying storage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, listed row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, AocError> {
+        if width * height != cells.len() {
+            return Err(AocError::new(format!(
+                "a {}x{} grid needs {} cells, found {}",
+                width,
+                height,
+                width * height,
+                cells.len()
+            )));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Creates a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(AocError::new(format!(
+                "inconsistent row length: row {} has {} cells, expected {}",
+                y + 1,
+                rows[y].len(),
+                width
+            )));
+        }
+        let height = rows.len();
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses a map with one character per cell, converted by `parse`.
+    ///
+    /// Blank lines are ignored, and an empty map is an error.
+    pub fn parse_with<F>(input: &str, mut parse: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let rows = parse_lines(input, |line| {
+            if *width.get_or_insert(line.chars().count()) != line.chars().count() {
+                return Err(AocError::invalid(line, "inconsistent row length"));
+            }
+            line.chars()
+                .map(|c| {
+                    parse(c).ok_or_else(|| {
+                        AocError::invalid(line, format!("unexpected character {:?}", c))
+                    })
+                })
+                .collect()
+        })?;
+        if rows.is_empty() {
+            return Err(AocError::new("empty grid"));
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the index of the cell at `(x, y)`, if it is within the grid.
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Returns the coordinates of the cell at `index`, if it is within the grid.
+    pub fn coords_of(&self, index: usize) -> Option<(usize, usize)> {
+        (index < self.cells.len()).then(|| (index % self.width, index / self.width))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Iterates over the cells, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over the coordinates and the value of every cell, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Iterates over the coordinates of the cells above, below, left and right
+    /// of `(x, y)` which are within the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS_4)
+    }
+
+    /// Iterates over the coordinates of the cells around `(x, y)`, diagonals
+    /// included, which are within the grid.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS_8)
+    }
+
+    fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx)?;
+            let ny = y.checked_add_signed(dy)?;
+            self.index_of(nx, ny).map(|_| (nx, ny))
+        })
+    }
+
+    /// Returns the cells of the row `y`.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterates over the cells of the column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Returns a new grid with the value of every cell converted by `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Returns a new grid whose rows are the columns of this one.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a map of decimal digits, such as `2199943210`.
+    pub fn from_digits(input: &str) -> Result<Self, AocError> {
+        Grid::parse_with(input, |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map of characters, such as `#..#.`.
+    pub fn from_chars(input: &str) -> Result<Self, AocError> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "({}, {}) is out of a {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index_of(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "({}, {}) is out of a {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.cells[index]
+    }
+}
+
+/// Displays the grid one row per line, without separating the cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "123
+456
+";
+
+    #[test]
+    fn test_grid_addressing() {
+        let grid = Grid::from_digits(RAW_INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.index_of(1, 1), Some(4));
+        assert_eq!(grid.coords_of(4), Some((1, 1)));
+        assert_eq!(grid.coords_of(6), None);
+        assert_eq!(grid[4], 5);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let grid = Grid::from_digits(RAW_INPUT).unwrap();
+        let corner = grid.neighbors4(0, 0).collect::<Vec<_>>();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        let edge = grid.neighbors8(2, 1).collect::<Vec<_>>();
+        assert_eq!(edge, vec![(1, 0), (2, 0), (1, 1)]);
+        assert_eq!(grid.neighbors8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let grid = Grid::from_digits(RAW_INPUT).unwrap();
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "14\n25\n36");
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn test_grid_parse_errors() {
+        assert_eq!(
+            Grid::from_digits("123\n45").unwrap_err().to_string(),
+            "line 2: inconsistent row length: `45`"
+        );
+        assert!(Grid::from_digits("1a3").is_err());
+        assert!(Grid::from_chars("").is_err());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Grid::new(2, 2, vec!['#'; 3]).is_err());
+        assert_eq!(Grid::from_chars("#.\n.#").unwrap()[(1, 1)], '#');
+    }
+}
@@ -0,0 +1,310 @@
+use crate::bench::Benchmark;
+use crate::runner::format_duration;
+use crate::Part;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The default file holding the benchmark history.
+pub const DEFAULT_HISTORY: &str = "bench_history.tsv";
+
+const HEADER: &str = "# timestamp\trevision\tpuzzle\truns\tparse median (ns)\tsolve median (ns)";
+
+/// The median timings of a solver, as recorded in the benchmark history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The git revision the benchmark ran on.
+    pub revision: String,
+    pub day: u8,
+    pub part: Part,
+    pub runs: usize,
+    pub parse_median: Duration,
+    pub solve_median: Duration,
+}
+
+impl Record {
+    /// Returns the record of a benchmark, unless the solver failed.
+    pub fn from_benchmark(benchmark: &Benchmark, timestamp: u64, revision: &str) -> Option<Self> {
+        let timings = benchmark.result.as_ref().ok()?;
+        Some(Record {
+            timestamp,
+            revision: revision.to_string(),
+            day: benchmark.day,
+            part: benchmark.part,
+            runs: timings.runs,
+            parse_median: timings.parse.median,
+            solve_median: timings.solve.median,
+        })
+    }
+
+    /// Returns the median time to parse the input and solve the puzzle.
+    pub fn total(&self) -> Duration {
+        self.parse_median + self.solve_median
+    }
+}
+
+/// Formats a record as a line of the history file, with tab separated fields.
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\tday{:02}{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.revision,
+            self.day,
+            self.part,
+            self.runs,
+            self.parse_median.as_nanos(),
+            self.solve_median.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split('\t').collect::<Vec<&str>>();
+        let [timestamp, revision, puzzle, runs, parse, solve] = fields[..] else {
+            return Err(format!("expected 6 fields, found {}", fields.len()));
+        };
+        let number = |value: &str| {
+            value
+                .parse::<u64>()
+                .map_err(|e| format!("invalid number `{}`: {}", value, e))
+        };
+        let (day, part) = puzzle
+            .strip_prefix("day")
+            .filter(|id| id.len() == 3)
+            .map(|id| id.split_at(2))
+            .ok_or_else(|| format!("invalid puzzle `{}`", puzzle))?;
+        Ok(Record {
+            timestamp: number(timestamp)?,
+            revision: revision.to_string(),
+            day: day
+                .parse()
+                .map_err(|_| format!("invalid puzzle `{}`", puzzle))?,
+            part: part.parse()?,
+            runs: number(runs)? as usize,
+            parse_median: Duration::from_nanos(number(parse)?),
+            solve_median: Duration::from_nanos(number(solve)?),
+        })
+    }
+}
+
+/// The benchmark records, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Loads the history, which is empty when the file does not exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends records to the history file, creating it if needed.
+    pub fn append<P: AsRef<Path>>(path: P, records: &[Record]) -> io::Result<()> {
+        let is_new = !path.as_ref().exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if is_new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        for record in records {
+            writeln!(file, "{}", record)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the most recent record of a solver.
+    pub fn latest(&self, day: u8, part: Part) -> Option<&Record> {
+        self.records
+            .iter()
+            .rev()
+            .find(|r| r.day == day && r.part == part)
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+}
+
+impl FromStr for History {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let records = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+            .collect::<Result<Vec<Record>, String>>()?;
+        Ok(History { records })
+    }
+}
+
+/// Returns the number of seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Returns the short hash of the current git revision, suffixed with `-dirty`
+/// when tracked files were modified, or `unknown` outside of a repository.
+pub fn revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// How the median time of a solver changed since its last record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub previous: Record,
+    pub current: Record,
+    /// The relative change, e.g. `0.25` when 25% slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compares records with the latest ones of the history.
+///
+/// A solver regressed when its median time grew by more than `threshold`
+/// percent. Solvers without history are skipped.
+pub fn compare(history: &History, current: &[Record], threshold: u32) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|record| {
+            let previous = history.latest(record.day, record.part)?;
+            let before = previous.total().as_secs_f64();
+            let change = match before > 0.0 {
+                true => record.total().as_secs_f64() / before - 1.0,
+                false => 0.0,
+            };
+            Some(Comparison {
+                previous: previous.clone(),
+                current: record.clone(),
+                change,
+                regressed: change * 100.0 > threshold as f64,
+            })
+        })
+        .collect()
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{:02}{}  {} -> {}  {:+.1}% since {}",
+            self.current.day,
+            self.current.part,
+            format_duration(self.previous.total()),
+            format_duration(self.current.total()),
+            self.change * 100.0,
+            self.previous.revision
+        )?;
+        if self.regressed {
+            write!(f, "  REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_HISTORY: &str = "# timestamp\trevision\tpuzzle\truns\tparse\tsolve
+1639000000\tabc1234\tday06a\t10\t1000\t9000
+1639000000\tabc1234\tday06b\t10\t1000\t19000
+1639100000\tdef5678\tday06a\t10\t1000\t4000
+";
+
+    fn record(part: Part, solve_micros: u64) -> Record {
+        Record {
+            timestamp: 1639200000,
+            revision: "0123abc".to_string(),
+            day: 6,
+            part,
+            runs: 10,
+            parse_median: Duration::from_micros(1),
+            solve_median: Duration::from_micros(solve_micros),
+        }
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let history = RAW_HISTORY.parse::<History>().unwrap();
+        assert_eq!(history.records().len(), 3);
+        let latest = history.latest(6, Part::A).unwrap();
+        assert_eq!(latest.revision, "def5678");
+        assert_eq!(latest.total(), Duration::from_micros(5));
+        assert_eq!(history.latest(7, Part::A), None);
+        assert_eq!(latest.to_string().parse::<Record>().as_ref(), Ok(latest));
+        assert_eq!(
+            "1\tabc\tday06\t10\t1\t1".parse::<Record>(),
+            Err("invalid puzzle `day06`".to_string())
+        );
+        assert!("1\tabc\tday06a\t10\t1".parse::<Record>().is_err());
+        assert!("# comment\n1\tabc".parse::<History>().is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = RAW_HISTORY.parse::<History>().unwrap();
+        let current = [
+            record(Part::A, 5),
+            record(Part::B, 3),
+            Record {
+                day: 7,
+                ..record(Part::A, 1)
+            },
+        ];
+        let comparisons = compare(&history, &current, 10);
+        assert_eq!(comparisons.len(), 2);
+        assert!((comparisons[0].change - 0.2).abs() < 1e-9);
+        assert!(comparisons[0].regressed);
+        assert!((comparisons[1].change + 0.8).abs() < 1e-9);
+        assert!(!comparisons[1].regressed);
+        assert!(comparisons[0].to_string().ends_with("REGRESSION"));
+        assert!(!compare(&history, &current, 25)[0].regressed);
+    }
+
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!("aoc2021_history_{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+        History::append(&path, &[record(Part::A, 5)]).unwrap();
+        History::append(&path, &[record(Part::B, 7)]).unwrap();
+        let history = History::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(history.records(), &[record(Part::A, 5), record(Part::B, 7)]);
+    }
+}
@@ -0,0 +1,262 @@
+//! A minimal HTTP client for the Advent of Code website.
+//!
+//! Plain `http://` URLs are requested over a `TcpStream`, which is enough for
+//! a local server. The standard library has no TLS, so `https://` URLs are
+//! requested with `curl`.
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// How long to wait for the server before giving up.
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+const USER_AGENT: &str = "aoc2021 solutions (std-only client)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends a GET request with the given cookie.
+pub fn get(url: &str, cookie: &str) -> io::Result<Response> {
+    request("GET", url, cookie, None)
+}
+
+/// Sends a POST request with the given cookie and form encoded body.
+pub fn post_form(url: &str, cookie: &str, body: &str) -> io::Result<Response> {
+    request("POST", url, cookie, Some(body))
+}
+
+/// Encodes a form value, escaping everything but unreserved characters.
+pub fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn request(method: &str, url: &str, cookie: &str, body: Option<&str>) -> io::Result<Response> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        request_tcp(method, rest, cookie, body)
+    } else if url.starts_with("https://") {
+        request_curl(method, url, cookie, body)
+    } else {
+        Err(invalid(format!("unsupported URL `{}`", url)))
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn request_tcp(method: &str, url: &str, cookie: &str, body: Option<&str>) -> io::Result<Response> {
+    let (host, path) = match url.find('/') {
+        Some(i) => url.split_at(i),
+        None => (url, "/"),
+    };
+    let address = match host.contains(':') {
+        true => host.to_string(),
+        false => format!("{}:80", host),
+    };
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: {}\r\nConnection: close\r\n",
+        method, path, host, USER_AGENT, cookie
+    );
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    parse_response(&String::from_utf8_lossy(&response))
+}
+
+fn parse_response(response: &str) -> io::Result<Response> {
+    let malformed = || io::Error::new(io::ErrorKind::InvalidData, "malformed HTTP response");
+    let (head, body) = response.split_once("\r\n\r\n").ok_or_else(malformed)?;
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(malformed)?;
+    let chunked = lines.any(|line| {
+        line.to_ascii_lowercase()
+            .starts_with("transfer-encoding: chunked")
+    });
+    if chunked {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "chunked HTTP responses are not supported",
+        ));
+    }
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Runs `curl`, passing the cookie through its standard input rather than its
+/// arguments, which any local user can read.
+fn request_curl(method: &str, url: &str, cookie: &str, body: Option<&str>) -> io::Result<Response> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--request", method])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--config", "-"])
+        .args(["--write-out", "\n%{http_code}"]);
+    if let Some(body) = body {
+        command.args(["--data", body]);
+    }
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(curl_config(cookie).as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "missing HTTP status"))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Returns the `curl` configuration setting the cookie.
+fn curl_config(cookie: &str) -> String {
+    let escaped = cookie.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("cookie = \"{}\"\n", escaped)
+}
+
+/// A local HTTP server answering requests with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one connection per response, in order, then stops.
+    ///
+    /// Returns the base URL of the server, and a handle returning the
+    /// requests it received, head and body.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.strip_prefix("Content-Length: ") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body_bytes = vec![0; length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8(body_bytes).unwrap());
+                requests.push(request);
+                let response = format!(
+                    "HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_request() {
+        let (url, server) = mock::serve(vec![(200, "hello".into()), (404, "nope".into())]);
+        let response = get(&format!("{}/2021/day/1/input", url), "session=abc").unwrap();
+        assert_eq!(response.body, "hello");
+        assert!(response.is_success());
+        let response = post_form(&format!("{}/answer", url), "session=abc", "level=1").unwrap();
+        assert_eq!((response.status, response.body.as_str()), (404, "nope"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("POST /answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1"));
+    }
+
+    #[test]
+    fn test_request_curl() {
+        let (url, server) = mock::serve(vec![(200, "hello".into())]);
+        let response = request_curl("POST", &url, "session=abc", Some("level=1")).unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, "hello"));
+        let requests = server.join().unwrap();
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1"));
+
+        assert_eq!(curl_config(r#"a"b\c"#), "cookie = \"a\\\"b\\\\c\"\n");
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("1715"), "1715");
+        assert_eq!(form_encode("a b&c"), "a%20b%26c");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert!(parse_response("garbage").is_err());
+        let response = parse_response("HTTP/1.1 301 Moved\r\nLocation: /\r\n\r\n").unwrap();
+        assert_eq!((response.status, response.body.as_str()), (301, ""));
+    }
+}
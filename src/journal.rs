@@ -0,0 +1,267 @@
+use crate::submit::Feedback;
+use crate::Part;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The directory holding the journals of a year, in the assets directory of
+/// the year.
+pub const DEFAULT_JOURNAL: &str = "journal";
+
+const HEADER: &str = "# Rejected answers: <part> <answer> <high|low|wrong>";
+
+/// Why an answer was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    /// Rejected without telling whether it is too high or too low.
+    Wrong,
+}
+
+impl Hint {
+    /// Returns the hint of the feedback on a wrong answer.
+    pub fn from_feedback(feedback: &Feedback) -> Option<Self> {
+        match feedback {
+            Feedback::TooHigh => Some(Hint::TooHigh),
+            Feedback::TooLow => Some(Hint::TooLow),
+            Feedback::Wrong => Some(Hint::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(Hint::TooHigh),
+            "low" => Ok(Hint::TooLow),
+            "wrong" => Ok(Hint::Wrong),
+            _ => Err(format!(
+                "invalid hint `{}`: expected `high`, `low` or `wrong`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "high"),
+            Hint::TooLow => write!(f, "low"),
+            Hint::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// A rejected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub answer: String,
+    pub hint: Hint,
+}
+
+/// Formats an entry as a line of a journal, e.g. `a 530 low`.
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.part, self.answer, self.hint)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split_whitespace().collect::<Vec<&str>>();
+        let [part, answer, hint] = fields[..] else {
+            return Err("expected `<part> <answer> <high|low|wrong>`".to_string());
+        };
+        Ok(Entry {
+            part: part.parse()?,
+            answer: answer.to_string(),
+            hint: hint.parse()?,
+        })
+    }
+}
+
+/// Why an answer should not be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// The very same answer was rejected.
+    Rejected(Hint),
+    /// The answer is not below an answer rejected as too high.
+    NotBelow(i64),
+    /// The answer is not above an answer rejected as too low.
+    NotAbove(i64),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::Rejected(Hint::Wrong) => write!(f, "this answer was already rejected"),
+            Warning::Rejected(hint) => {
+                write!(f, "this answer was already rejected as too {}", hint)
+            }
+            Warning::NotBelow(bound) => write!(f, "{} was already too high", bound),
+            Warning::NotAbove(bound) => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+/// The answers of a day rejected by the website, oldest first.
+///
+/// Stored as `<DIR>/YYYY/journal/dayNN.txt` in the assets directory, with one
+/// entry per line formatted as `<part> <answer> <high|low|wrong>`, and lines
+/// starting with `#` being comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+    entries: Vec<Entry>,
+}
+
+impl Journal {
+    /// Returns the path of the journal of a day.
+    pub fn path<P: AsRef<Path>>(dir: P, day: u8) -> PathBuf {
+        dir.as_ref().join(format!("day{:02}.txt", day))
+    }
+
+    /// Loads the journal of a day, which is empty when the file does not
+    /// exist yet.
+    pub fn load<P: AsRef<Path>>(dir: P, day: u8) -> io::Result<Self> {
+        match fs::read_to_string(Journal::path(dir, day)) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Journal::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends an entry to the journal of a day, creating it if needed.
+    pub fn append<P: AsRef<Path>>(dir: P, day: u8, entry: &Entry) -> io::Result<()> {
+        fs::create_dir_all(&dir)?;
+        let path = Journal::path(dir, day);
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if is_new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        writeln!(file, "{}", entry)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the largest answer rejected as too low, and the smallest one
+    /// rejected as too high.
+    pub fn bounds(&self, part: Part) -> (Option<i64>, Option<i64>) {
+        let rejected = |hint: Hint| {
+            self.entries
+                .iter()
+                .filter(move |e| e.part == part && e.hint == hint)
+                .filter_map(|e| e.answer.parse::<i64>().ok())
+        };
+        (rejected(Hint::TooLow).max(), rejected(Hint::TooHigh).min())
+    }
+
+    /// Tells why an answer should not be submitted, if it should not.
+    pub fn check(&self, part: Part, answer: &str) -> Option<Warning> {
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|e| e.part == part && e.answer == answer)
+        {
+            return Some(Warning::Rejected(entry.hint));
+        }
+        let answer = answer.parse::<i64>().ok()?;
+        match self.bounds(part) {
+            (_, Some(high)) if answer >= high => Some(Warning::NotBelow(high)),
+            (Some(low), _) if answer <= low => Some(Warning::NotAbove(low)),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Journal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+            .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+            .collect::<Result<Vec<Entry>, String>>()?;
+        Ok(Journal { entries })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_JOURNAL: &str = "# Rejected answers.
+a 530 low
+a 1200 high
+a 700 wrong
+b 42 high
+";
+
+    #[test]
+    fn test_parse_journal() {
+        let journal = RAW_JOURNAL.parse::<Journal>().unwrap();
+        assert_eq!(journal.entries().len(), 4);
+        assert_eq!(journal.entries()[0].to_string(), "a 530 low");
+        assert_eq!(journal.bounds(Part::A), (Some(530), Some(1200)));
+        assert_eq!(journal.bounds(Part::B), (None, Some(42)));
+        assert_eq!(
+            "a 530".parse::<Journal>().unwrap_err(),
+            "line 1: expected `<part> <answer> <high|low|wrong>`"
+        );
+        assert!("a 530 big".parse::<Journal>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let journal = RAW_JOURNAL.parse::<Journal>().unwrap();
+        assert_eq!(journal.check(Part::A, "600"), None);
+        assert_eq!(
+            journal.check(Part::A, "700"),
+            Some(Warning::Rejected(Hint::Wrong))
+        );
+        assert_eq!(journal.check(Part::A, "500"), Some(Warning::NotAbove(530)));
+        assert_eq!(
+            journal.check(Part::A, "1300"),
+            Some(Warning::NotBelow(1200))
+        );
+        assert_eq!(journal.check(Part::B, "41"), None);
+        assert_eq!(journal.check(Part::B, "abc"), None);
+        assert_eq!(
+            journal.check(Part::A, "530").unwrap().to_string(),
+            "this answer was already rejected as too low"
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let dir = std::env::temp_dir().join(format!("aoc2021_journal_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(Journal::load(&dir, 9).unwrap(), Journal::default());
+        let entry = Entry {
+            part: Part::A,
+            answer: "530".to_string(),
+            hint: Hint::TooLow,
+        };
+        Journal::append(&dir, 9, &entry).unwrap();
+        Journal::append(&dir, 9, &entry).unwrap();
+        let journal = Journal::load(&dir, 9).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(journal.entries(), &[entry.clone(), entry]);
+    }
+}
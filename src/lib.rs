@@ -1,110 +1,292 @@
+use std::env;
 use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-
-pub fn read_input<P, T>(input: P) -> Vec<T>
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod history;
+pub mod http;
+pub mod journal;
+pub mod log;
+pub mod point;
+pub mod property;
+pub mod repl;
+pub mod runner;
+pub mod scaffold;
+pub mod server;
+pub mod solver;
+pub mod stats;
+pub mod submit;
+pub mod y2021;
+
+pub use error::AocError;
+pub use graph::{Graph, NodeId};
+pub use grid::Grid;
+pub use point::{Point, Point3};
+pub use solver::{Answer, Part, Registry, Run, Solution, Solver};
+
+/// A year of puzzles hosted by the crate.
+pub struct Year {
+    pub year: u16,
+    pub register: fn(&mut Registry),
+    pub register_probes: fn(&mut repl::Probes),
+    pub register_generators: fn(&mut generate::Generators),
+}
+
+/// The year used unless another one is asked for.
+pub const DEFAULT_YEAR: u16 = 2021;
+
+/// The years hosted by the crate, oldest first.
+///
+/// A new year gets its own `yYYYY` module, registering its days like
+/// `y2021`, and an entry here.
+pub const YEARS: &[Year] = &[Year {
+    year: 2021,
+    register: y2021::register,
+    register_probes: y2021::register_probes,
+    register_generators: y2021::register_generators,
+}];
+
+/// Returns a hosted year.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Returns a registry holding the solvers of every day of a year, which is
+/// empty if the year is not hosted.
+pub fn registry(year: u16) -> Registry {
+    let mut registry = Registry::new();
+    if let Some(year) = crate::year(year) {
+        (year.register)(&mut registry);
+    }
+    registry
+}
+
+/// Returns the probes the days of a year expose to the `repl`.
+pub fn probes(year: u16) -> repl::Probes {
+    let mut probes = repl::Probes::new();
+    if let Some(year) = crate::year(year) {
+        (year.register_probes)(&mut probes);
+    }
+    probes
+}
+
+/// Returns the input generators of the days of a year.
+pub fn generators(year: u16) -> generate::Generators {
+    let mut generators = generate::Generators::new();
+    if let Some(year) = crate::year(year) {
+        (year.register_generators)(&mut generators);
+    }
+    generators
+}
+
+/// Environment variable overriding the directory holding the puzzle inputs.
+pub const ASSETS_DIR_ENV: &str = "AOC_ASSETS_DIR";
+
+/// Returns the directory holding the puzzle inputs, one directory per
+/// year.
+///
+/// Defaults to `assets`, relative to the current directory, unless
+/// `AOC_ASSETS_DIR` is set.
+pub fn assets_dir() -> PathBuf {
+    env::var_os(ASSETS_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("assets"))
+}
+
+/// Returns the directory of a given year within `dir`, e.g. `assets/2021`.
+pub fn year_dir<P: AsRef<Path>>(dir: P, year: u16) -> PathBuf {
+    dir.as_ref().join(year.to_string())
+}
+
+/// Returns the path of the puzzle input of a given day within `dir`, the
+/// directory of its year.
+pub fn asset_path_in<P: AsRef<Path>>(dir: P, day: u8) -> PathBuf {
+    dir.as_ref().join(format!("day{:02}.txt", day))
+}
+
+/// Returns the path of the puzzle input of a given day.
+pub fn asset_path(year: u16, day: u8) -> PathBuf {
+    asset_path_in(year_dir(assets_dir(), year), day)
+}
+
+/// Reads the puzzle input of a given day.
+pub fn read_asset(year: u16, day: u8) -> Result<String, AocError> {
+    read_file(asset_path(year, day)).map_err(|e| e.with_day(day))
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> Result<String, AocError> {
+    fs::read_to_string(&path)
+        .map_err(|e| AocError::new(format!("could not load {}: {}", path.as_ref().display(), e)))
+}
+
+/// Reads a file holding one value per line.
+///
+/// Fails on the first line which cannot be parsed, see `input_from_string`.
+pub fn read_input<P, T>(input: P) -> Result<Vec<T>, AocError>
 where
     P: AsRef<Path>,
     T: FromStr,
+    T::Err: fmt::Display,
+{
+    input_from_string::<T>(&read_file(input)?)
+}
+
+/// Parses one value per line, ignoring blank lines.
+///
+/// Fails with the line number of the first value which cannot be parsed, or
+/// if there is no value at all.
+pub fn input_from_string<T>(input: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
 {
-    let values = fs::read_to_string(input).expect("Could not load file");
-    input_from_string::<T>(&values)
+    non_empty(parse_lines(input, parse_value))
 }
 
-pub fn input_from_string<T>(input: &str) -> Vec<T>
+/// Parses one value per line, silently skipping the lines which cannot be
+/// parsed.
+pub fn input_from_string_lenient<T>(input: &str) -> Vec<T>
 where
     T: FromStr,
 {
     input
         .lines()
-        .filter_map(|s| s.parse::<T>().ok())
+        .filter_map(|s| s.trim().parse::<T>().ok())
         .collect::<Vec<T>>()
 }
 
-pub fn read_input_sep<P, T>(input: P, separator: &str) -> Vec<T>
+/// Reads a file holding values delimited by `separator`.
+///
+/// Fails on the first field which cannot be parsed, see
+/// `input_sep_from_string`.
+pub fn read_input_sep<P, T>(input: P, separator: &str) -> Result<Vec<T>, AocError>
 where
     P: AsRef<Path>,
     T: FromStr,
+    T::Err: fmt::Display,
 {
-    let values = fs::read_to_string(input).expect("Could not load file");
-    input_sep_from_string::<T>(&values, separator)
+    input_sep_from_string::<T>(&read_file(input)?, separator)
 }
 
-pub fn input_sep_from_string<T>(input: &str, separator: &str) -> Vec<T>
+/// Parses values delimited by `separator`.
+///
+/// Fails with the field number of the first value which cannot be parsed, or
+/// if there is no value at all.
+pub fn input_sep_from_string<T>(input: &str, separator: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    non_empty(parse_fields(input, separator, parse_value))
+}
+
+/// Parses values delimited by `separator`, silently skipping the ones which
+/// cannot be parsed.
+pub fn input_sep_from_string_lenient<T>(input: &str, separator: &str) -> Vec<T>
 where
     T: FromStr,
 {
     input
         .trim()
         .split(separator)
-        .filter_map(|s| s.parse::<T>().ok())
+        .filter_map(|s| s.trim().parse::<T>().ok())
         .collect::<Vec<T>>()
 }
 
-pub fn mean(list: &[i32]) -> i32 {
-    list.iter().sum::<i32>() / (list.len() as i32)
+fn parse_value<T>(value: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .parse::<T>()
+        .map_err(|e| AocError::invalid(value, format!("invalid value: {}", e)))
 }
 
-pub fn median(list: &[i32]) -> i32 {
-    let mut v = list.iter().copied().collect::<Vec<i32>>();
-    v.sort();
-    let len = v.len();
-    let mid = len / 2;
-    if len % 2 == 0 {
-        return *v.get(mid + 1).unwrap();
-    } else {
-        return v[mid];
+fn non_empty<T>(values: Result<Vec<T>, AocError>) -> Result<Vec<T>, AocError> {
+    match values {
+        Ok(values) if values.is_empty() => Err(AocError::new("empty input")),
+        values => values,
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    x: i32,
-    y: i32,
+/// Parses each field of the input delimited by `separator`, recording the
+/// field number of the first error.
+pub fn parse_fields<'a, T, F>(
+    input: &'a str,
+    separator: &str,
+    mut parse: F,
+) -> Result<Vec<T>, AocError>
+where
+    F: FnMut(&'a str) -> Result<T, AocError>,
+{
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+    input
+        .split(separator)
+        .enumerate()
+        .map(|(i, field)| parse(field.trim()).map_err(|e| e.with_field(i + 1)))
+        .collect()
 }
 
-impl Point {
-    pub fn from_string(input: &str) -> Self {
-        match input.split_once(',') {
-            Some((x, y)) => Point {
-                x: x.parse::<i32>().unwrap(),
-                y: y.parse::<i32>().unwrap(),
-            },
-            None => panic!("cannot parse the string representing the point: {}", input),
-        }
-    }
+/// Parses each non-blank line of the input, recording the line number of the
+/// first error.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parse: F) -> Result<Vec<T>, AocError>
+where
+    F: FnMut(&'a str) -> Result<T, AocError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse(line).map_err(|e| e.with_line(i + 1)))
+        .collect()
 }
 
-impl From<&str> for Point {
-    fn from(item: &str) -> Self {
-        match item.split_once(',') {
-            Some((x, y)) => Point {
-                x: x.parse::<i32>().unwrap(),
-                y: y.parse::<i32>().unwrap(),
-            },
-            None => panic!("cannot parse the string representing the point: {}", item),
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_from_string() {
+        assert_eq!(input_from_string::<i32>("1\n 2\n\n3\n"), Ok(vec![1, 2, 3]));
+        let error = input_from_string::<i32>("1\n2\nx\n4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: invalid value: invalid digit found in string: `x`"
+        );
+        assert!(input_from_string::<i32>("\n").is_err());
+        assert_eq!(input_from_string_lenient::<i32>("1\nx\n3"), vec![1, 3]);
     }
-}
 
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{},{}", self.x, self.y)
+    #[test]
+    fn test_input_sep_from_string() {
+        assert_eq!(
+            input_sep_from_string::<u8>("3,4,3,1,2\n", ","),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+        let error = input_sep_from_string::<u8>("3,4,,1", ",").unwrap_err();
+        assert_eq!(error.field(), Some(3));
+        assert!(input_sep_from_string::<u8>("", ",").is_err());
+        assert_eq!(
+            input_sep_from_string_lenient::<u8>("3,4,,1", ","),
+            vec![3, 4, 1]
+        );
     }
 }
@@ -0,0 +1,133 @@
+//! A minimal leveled logger writing to stderr.
+//!
+//! Only warnings are logged unless a more verbose level is set, so the
+//! solvers stay quiet on real runs. Use the `warn!`, `info!`, `debug!` and
+//! `trace!` macros, which format their message only when its level is
+//! enabled:
+//!
+//! ```
+//! aoc2021::debug!("polymer of {} elements", 42);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How detailed a message is, from the least to the most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Something went wrong but the program carries on, e.g. a connection
+    /// which could not be accepted.
+    Warn = 1,
+    /// Noteworthy events, e.g. a simulation reaching a fixed point.
+    Info = 2,
+    /// Intermediate results.
+    Debug = 3,
+    /// Every step of a computation.
+    Trace = 4,
+}
+
+impl Level {
+    /// Returns the level enabled by a number of `-v` flags.
+    pub fn from_verbosity(verbosity: usize) -> Self {
+        match verbosity {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "invalid log level `{}`: expected `warn`, `info`, `debug` or `trace`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Warn => write!(f, "WARN"),
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// The most verbose level logged, 0 when logging is off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the most verbose level logged, or turns logging off.
+pub fn set_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes a message to stderr if its level is enabled, see the macros.
+pub fn log(level: Level, target: &str, message: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("[{} {}] {}", level, target, message);
+    }
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Warn, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Info, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Debug, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log::log($crate::log::Level::Trace, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("debug".parse::<Level>(), Ok(Level::Debug));
+        assert!("verbose".parse::<Level>().is_err());
+        assert_eq!("warn".parse::<Level>(), Ok(Level::Warn));
+        assert!("verbose".parse::<Level>().is_err());
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+        assert!(Level::Warn < Level::Info);
+        assert!(Level::Info < Level::Trace);
+    }
+}
@@ -1,49 +1,358 @@
-use aoc2021::day01::{day01a, day01b};
-use aoc2021::day02::{day02a, day02b};
-use aoc2021::day03::{day03a, day03b};
-use aoc2021::day04::{day04a, day04b};
-use aoc2021::day05::{day05a, day05b};
-use aoc2021::day06::{day06a, day06b};
-use aoc2021::day07::{day07a, day07b};
-use aoc2021::day08::day08a;
-use aoc2021::day09::day09a;
-use aoc2021::day10::{day10a, day10b};
-use aoc2021::day11::{day11a, day11b};
-use aoc2021::day12::{day12a, day12b};
-use aoc2021::day13::{day13a, day13b};
-use aoc2021::day14::{day14a, day14b};
+use aoc2021::answers::{summary, verify, Answers};
+use aoc2021::bench::{bench_all, BenchTable};
+use aoc2021::cli::{Command, Options, Target, USAGE};
+use aoc2021::examples::{check_examples, load_examples, DEFAULT_EXAMPLES};
+use aoc2021::fetch::{fetch_input, Fetched};
+use aoc2021::generate::Rng;
+use aoc2021::history::{self, History, Record};
+use aoc2021::journal::{Entry, Hint, Journal};
+use aoc2021::repl::{self, Repl};
+use aoc2021::runner::{run_parallel, Report, Table};
+use aoc2021::scaffold::{self, Layout};
+use aoc2021::server::Server;
+use aoc2021::submit::{submit_answer, Feedback};
+use aoc2021::{generators, info, log, probes, registry, year_dir, Part, Solver};
+use std::process;
+use std::sync::Arc;
 
 fn main() {
-    let args = std::env::args().collect::<Vec<String>>();
-    let problem = args.get(1).map(|s| s.as_str()).unwrap_or("None");
-    let result = match problem {
-        "day01a" => day01a(),
-        "day01b" => day01b(),
-        "day02a" => day02a(),
-        "day02b" => day02b(),
-        "day03a" => day03a(),
-        "day03b" => day03b(),
-        "day04a" => day04a(),
-        "day04b" => day04b(),
-        "day05a" => day05a(),
-        "day05b" => day05b(),
-        "day06a" => day06a(),
-        "day06b" => day06b(),
-        "day07a" => day07a(),
-        "day07b" => day07b(),
-        "day08a" => day08a(),
-        "day09a" => day09a(),
-        "day10a" => day10a(),
-        "day10b" => day10b(),
-        "day11a" => day11a(),
-        "day11b" => day11b(),
-        "day12a" => day12a(),
-        "day12b" => day12b(),
-        "day13a" => day13a(),
-        "day13b" => day13b(),
-        "day14a" => day14a(),
-        // "day14b" => day14b(),
-        _ => "We haven't solved that yet".to_string(),
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
     };
-    println!("{}", result);
+    log::set_level(options.log_level);
+    let registry = registry(options.year);
+    let journal_dir = options.journal_dir();
+
+    match &options.command {
+        Command::Help => println!("{}", USAGE),
+        Command::List => {
+            for solver in registry.iter() {
+                println!("{}  {}", solver.id(), solver.name());
+            }
+        }
+        Command::All => print_reports(&run_parallel(
+            registry.iter(),
+            |day| options.read_input(day),
+            options.jobs,
+        )),
+        Command::Day(day) => {
+            if registry.day(*day).next().is_none() {
+                println!("We haven't solved that yet");
+                process::exit(1);
+            }
+            print_reports(&run_parallel(
+                registry.day(*day),
+                |day| options.read_input(day),
+                options.jobs,
+            ))
+        }
+        Command::Verify => {
+            let path = options.answers_path();
+            let answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("error: could not load {}: {}", path.display(), e);
+                    process::exit(1);
+                }
+            };
+            let reports =
+                run_parallel(registry.iter(), |day| options.read_input(day), options.jobs);
+            let verifications = verify(&reports, &answers);
+            for verification in verifications.iter() {
+                println!("{}", verification);
+            }
+            println!("\n{}", summary(verifications.iter().map(|v| &v.verdict)));
+            if !verifications.iter().all(|v| v.is_ok()) {
+                process::exit(1);
+            }
+        }
+        Command::Examples => {
+            let examples = match load_examples(year_dir(DEFAULT_EXAMPLES, options.year)) {
+                Ok(examples) => examples,
+                Err(e) => {
+                    eprintln!("error: could not load the examples: {}", e);
+                    process::exit(1);
+                }
+            };
+            let checks = check_examples(&registry, &examples);
+            for check in checks.iter() {
+                println!("{}", check);
+            }
+            println!(
+                "\n{}",
+                summary(checks.iter().map(|c| &c.verification.verdict))
+            );
+            if !checks.iter().all(|c| c.verification.is_ok()) {
+                process::exit(1);
+            }
+        }
+        Command::Bench(target) => {
+            let solvers: Vec<&dyn Solver> = match target {
+                Target::All => registry.iter().collect(),
+                Target::Day(day) => registry.day(*day).collect(),
+                Target::Puzzle(problem) => registry.find(problem).into_iter().collect(),
+            };
+            if solvers.is_empty() {
+                println!("We haven't solved that yet");
+                process::exit(1);
+            }
+            let benchmarks = bench_all(
+                solvers,
+                |day| options.read_input(day),
+                options.warmup,
+                options.runs,
+            );
+            print!("{}", BenchTable(&benchmarks));
+
+            let (timestamp, revision) = (history::now(), history::revision());
+            let records = benchmarks
+                .iter()
+                .filter_map(|b| Record::from_benchmark(b, timestamp, &revision))
+                .collect::<Vec<Record>>();
+            let path = options.history_path();
+            let mut regressed = false;
+            if options.compare {
+                let history = match History::load(&path) {
+                    Ok(history) => history,
+                    Err(e) => {
+                        eprintln!("error: could not load {}: {}", path.display(), e);
+                        process::exit(1);
+                    }
+                };
+                let comparisons = history::compare(&history, &records, options.threshold);
+                println!();
+                if comparisons.is_empty() {
+                    println!("No previous benchmark to compare with");
+                }
+                for comparison in comparisons.iter() {
+                    println!("{}", comparison);
+                }
+                regressed = comparisons.iter().any(|c| c.regressed);
+            }
+            if let Err(e) = History::append(&path, &records) {
+                eprintln!("error: could not update {}: {}", path.display(), e);
+                process::exit(1);
+            }
+            if regressed || !benchmarks.iter().all(|b| b.result.is_ok()) {
+                process::exit(1);
+            }
+        }
+        Command::New(day) => {
+            let layout = Layout {
+                assets_dir: options.year_assets_dir(),
+                ..Layout::new("", options.year)
+            };
+            match scaffold::new_day(&layout, *day) {
+                Ok(paths) => {
+                    for path in paths {
+                        println!("wrote {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("error: could not create day{:02}: {}", day, e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Fetch(day) => {
+            let fetched = options.load_config().and_then(|config| {
+                fetch_input(&config, options.year_assets_dir(), options.year, *day)
+            });
+            match fetched {
+                Ok(Fetched::Cached(path)) => println!("{} is already there", path.display()),
+                Ok(Fetched::Downloaded(path)) => println!("wrote {}", path.display()),
+                Err(e) => {
+                    eprintln!("error: could not fetch the input of day{:02}: {}", day, e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Submit(day, part) => {
+            let Some(solver) = registry.get(*day, *part) else {
+                println!("We haven't solved that yet");
+                process::exit(1);
+            };
+            let answer = match options.read_input(*day) {
+                Ok(input) => solver.solve(&input),
+                Err(e) => {
+                    eprintln!("error: could not load the input of {}: {}", solver.id(), e);
+                    process::exit(1);
+                }
+            };
+            let answer = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            };
+            let journal = match Journal::load(&journal_dir, *day) {
+                Ok(journal) => journal,
+                Err(e) => {
+                    eprintln!("error: could not load the journal of day{:02}: {}", day, e);
+                    process::exit(1);
+                }
+            };
+            if let Some(warning) = journal.check(*part, &answer) {
+                println!("{} for {}: {}", answer, solver.id(), warning);
+                if !options.force {
+                    println!("Not submitted, use --force to submit it anyway");
+                    process::exit(1);
+                }
+            }
+            println!("Submitting {} for {}", answer, solver.id());
+            let feedback = options.load_config().and_then(|config| {
+                submit_answer(
+                    &config,
+                    options.cooldown_path(),
+                    history::now(),
+                    options.year,
+                    *day,
+                    *part,
+                    &answer,
+                )
+            });
+            match feedback {
+                Ok(Feedback::Correct) => println!("{}", Feedback::Correct),
+                Ok(feedback) => {
+                    println!("{}", feedback);
+                    if let Some(hint) = Hint::from_feedback(&feedback) {
+                        let entry = Entry {
+                            part: *part,
+                            answer,
+                            hint,
+                        };
+                        match Journal::append(&journal_dir, *day, &entry) {
+                            Ok(()) => println!(
+                                "Recorded in {}",
+                                Journal::path(&journal_dir, *day).display()
+                            ),
+                            Err(e) => eprintln!("error: could not record the answer: {}", e),
+                        }
+                    }
+                    process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("error: could not submit {}: {}", solver.id(), e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Journal(day, None) => {
+            let journal = match Journal::load(&journal_dir, *day) {
+                Ok(journal) => journal,
+                Err(e) => {
+                    eprintln!("error: could not load the journal of day{:02}: {}", day, e);
+                    process::exit(1);
+                }
+            };
+            for entry in journal.entries() {
+                println!(
+                    "day{:02}{}  {}  {}",
+                    day, entry.part, entry.answer, entry.hint
+                );
+            }
+            for part in Part::all() {
+                match journal.bounds(part) {
+                    (None, None) => (),
+                    (low, high) => println!(
+                        "day{:02}{} is between {} and {}",
+                        day,
+                        part,
+                        low.map_or("?".to_string(), |low| low.to_string()),
+                        high.map_or("?".to_string(), |high| high.to_string())
+                    ),
+                }
+            }
+        }
+        Command::Journal(day, Some(entry)) => {
+            if let Err(e) = Journal::append(&journal_dir, *day, entry) {
+                eprintln!("error: could not record the answer: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Serve => {
+            let served = Server::bind(&options.listen, Arc::new(registry), options.limits)
+                .and_then(|server| {
+                    println!("Listening on http://{}", server.local_addr()?);
+                    server.run()
+                });
+            if let Err(e) = served {
+                eprintln!("error: could not serve on {}: {}", options.listen, e);
+                process::exit(1);
+            }
+        }
+        Command::Repl => {
+            let probes = probes(options.year);
+            let history_path = options.repl_history_path();
+            let history = repl::load_history(&history_path).unwrap_or_else(|e| {
+                eprintln!("warning: could not load the history: {}", e);
+                Vec::new()
+            });
+            let shell =
+                Repl::new(&registry, &probes, options.year_assets_dir()).with_history(history);
+            let stdin = std::io::stdin();
+            if let Err(e) = repl::run(
+                shell,
+                stdin.lock(),
+                std::io::stdout(),
+                Some(history_path.as_ref()),
+            ) {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Generate(day) => {
+            let generators = generators(options.year);
+            let Some(generator) = generators.get(*day) else {
+                eprintln!("error: there is no generator for day{:02}", day);
+                process::exit(1);
+            };
+            let (unit, default_size) = generator.size;
+            let size = options.size.unwrap_or(default_size);
+            info!(
+                "generating day{:02} with {} {} and seed {}",
+                day, size, unit, options.seed
+            );
+            print!(
+                "{}",
+                (generator.generate)(&mut Rng::new(options.seed), size)
+            );
+        }
+        Command::Solve(problem) => {
+            let solver = match registry.find(problem) {
+                Some(solver) => solver,
+                None => {
+                    println!("We haven't solved that yet");
+                    process::exit(1);
+                }
+            };
+            let input = match options.read_input(solver.day()) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("error: could not load the input of {}: {}", solver.id(), e);
+                    process::exit(1);
+                }
+            };
+            match solver.solve(&input) {
+                Ok(answer) => println!("{}", answer),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// Prints the reports and exits with an error if any of the solvers failed.
+fn print_reports(reports: &[Report]) {
+    print!("{}", Table(reports));
+    if !reports.iter().all(Report::is_success) {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,369 @@
+use crate::AocError;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point on a 2D plane, or a vector between two points.
+///
+/// Points are ordered by `x`, then by `y`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// A point in a 3D space, or a vector between two points.
+///
+/// Points are ordered by `x`, then by `y`, then by `z`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    /// Returns the point with each coordinate replaced by its sign, which is
+    /// the unit step from the origin towards this point.
+    pub fn signum(&self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Returns the taxicab distance between two points.
+    pub fn manhattan(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Returns the number of king moves between two points.
+    pub fn chebyshev(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Iterates over the points above, below, left and right of this one.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .map(move |(dx, dy)| point + Point::new(dx, dy))
+    }
+
+    /// Iterates over the points around this one, diagonals included.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|&delta| delta != Point::ORIGIN)
+            .map(move |delta| point + delta)
+    }
+
+    /// Returns the smallest and largest corners of the box enclosing `points`.
+    pub fn bounding_box<'a, I>(points: I) -> Option<(Point, Point)>
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        points.into_iter().fold(None, |bounds, &p| match bounds {
+            None => Some((p, p)),
+            Some((min, max)) => Some((
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )),
+        })
+    }
+
+    /// Tells whether the point is within the box going from `min` to `max`,
+    /// both included.
+    pub fn is_within(&self, min: &Point, max: &Point) -> bool {
+        (min.x..=max.x).contains(&self.x) && (min.y..=max.y).contains(&self.y)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    pub fn z(&self) -> i32 {
+        self.z
+    }
+
+    /// Returns the point with each coordinate replaced by its sign.
+    pub fn signum(&self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Returns the taxicab distance between two points.
+    pub fn manhattan(&self, other: &Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns the largest difference between the coordinates of two points.
+    pub fn chebyshev(&self, other: &Point3) -> i32 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// Iterates over the 6 points sharing a face with this one.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Point3> {
+        let point = *self;
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| point + Point3::new(dx, dy, dz))
+    }
+
+    /// Iterates over the 26 points around this one, diagonals included.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Point3> {
+        let point = *self;
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .map(|(dx, dy, dz)| Point3::new(dx, dy, dz))
+            .filter(|&delta| delta != Point3::ORIGIN)
+            .map(move |delta| point + delta)
+    }
+
+    /// Returns the smallest and largest corners of the box enclosing `points`.
+    pub fn bounding_box<'a, I>(points: I) -> Option<(Point3, Point3)>
+    where
+        I: IntoIterator<Item = &'a Point3>,
+    {
+        points.into_iter().fold(None, |bounds, &p| match bounds {
+            None => Some((p, p)),
+            Some((min, max)) => Some((
+                Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )),
+        })
+    }
+
+    /// Tells whether the point is within the box going from `min` to `max`,
+    /// both included.
+    pub fn is_within(&self, min: &Point3, max: &Point3) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i32) -> $point {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point { x, y });
+impl_ops!(Point3 { x, y, z });
+
+/// Parses the comma separated coordinates of a point.
+fn parse_coordinates<const N: usize>(s: &str, format: &str) -> Result<[i32; N], AocError> {
+    let fields = s.split(',').collect::<Vec<&str>>();
+    if fields.len() != N {
+        return Err(AocError::invalid(
+            s,
+            format!("expected a point formatted as `{}`", format),
+        ));
+    }
+    let mut coordinates = [0; N];
+    for (coordinate, value) in coordinates.iter_mut().zip(fields) {
+        *coordinate = value
+            .trim()
+            .parse::<i32>()
+            .map_err(|e| AocError::invalid(s, format!("invalid coordinate `{}`: {}", value, e)))?;
+    }
+    Ok(coordinates)
+}
+
+impl FromStr for Point {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coordinates(s, "x,y")?;
+        Ok(Point { x, y })
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s, "x,y,z")?;
+        Ok(Point3 { x, y, z })
+    }
+}
+
+impl TryFrom<&str> for Point {
+    type Error = AocError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        item.parse()
+    }
+}
+
+impl TryFrom<&str> for Point3 {
+    type Error = AocError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        item.parse()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<(i32, i32, i32)> for Point3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, -4));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert!(Point::new(0, 9) < Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_point_neighbors() {
+        let p = Point::new(3, 3);
+        assert_eq!(p.neighbors4().count(), 4);
+        assert!(p.neighbors4().all(|n| n.manhattan(&p) == 1));
+        assert_eq!(p.neighbors8().count(), 8);
+        assert!(p.neighbors8().all(|n| n.chebyshev(&p) == 1));
+        let p = Point3::new(0, 0, 0);
+        assert_eq!(p.neighbors6().count(), 6);
+        assert_eq!(p.neighbors26().count(), 26);
+        assert!(p.neighbors26().all(|n| n.chebyshev(&p) == 1));
+    }
+
+    #[test]
+    fn test_point_bounding_box() {
+        let points = [Point::new(3, -1), Point::new(0, 4), Point::new(2, 2)];
+        let (min, max) = Point::bounding_box(&points).unwrap();
+        assert_eq!((min, max), (Point::new(0, -1), Point::new(3, 4)));
+        assert!(points.iter().all(|p| p.is_within(&min, &max)));
+        assert!(!Point::new(4, 0).is_within(&min, &max));
+        assert_eq!(Point::bounding_box(&[]), None);
+        let points = [Point3::new(1, 2, 3), Point3::new(-1, 5, 0)];
+        assert_eq!(
+            Point3::bounding_box(&points),
+            Some((Point3::new(-1, 2, 0), Point3::new(1, 5, 3)))
+        );
+    }
+
+    #[test]
+    fn test_point_parse() {
+        assert_eq!("6,10".parse::<Point>(), Ok(Point::new(6, 10)));
+        assert_eq!(Point::new(-3, 1).to_string().parse(), Ok(Point::new(-3, 1)));
+        assert_eq!("1,-2,3".parse::<Point3>(), Ok(Point3::new(1, -2, 3)));
+        assert_eq!(
+            "6".parse::<Point>().unwrap_err().to_string(),
+            "expected a point formatted as `x,y`: `6`"
+        );
+        assert!("1,2".parse::<Point3>().is_err());
+        assert!("1,x".parse::<Point>().is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Property tests: checking that a property holds on random inputs.
+//!
+//! When an input breaks the property, it is shrunk to a smaller one which
+//! still breaks it, so that the failure reported is easy to read. Mostly used
+//! to cross-check a naive implementation against an optimized one:
+//!
+//! ```
+//! use aoc2021::property::{agree, shrink_number, Checker};
+//!
+//! Checker::new().assert(
+//!     |rng| rng.below(1000),
+//!     |&n| shrink_number(n),
+//!     |&n| agree((0..=n).sum::<usize>(), n * (n + 1) / 2),
+//! );
+//! ```
+
+use crate::generate::Rng;
+use crate::runner::catch_panic;
+use std::fmt::{self, Debug};
+
+/// The number of inputs checked unless told otherwise.
+pub const DEFAULT_CASES: usize = 100;
+
+/// The seed used unless another one is given, so that failures reproduce.
+pub const DEFAULT_SEED: u64 = 2021;
+
+/// Gives up shrinking after this number of steps.
+const MAX_SHRINK_STEPS: usize = 1000;
+
+/// An input which breaks a property, before and after shrinking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<T> {
+    /// The number of the case which failed, starting at 1.
+    pub case: usize,
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    pub steps: usize,
+    /// Why the shrunk input breaks the property.
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "property failed on case {} with seed {}: {}\n  input: {:?}\n  original input: {:?} (shrunk in {} steps)",
+            self.case, self.seed, self.message, self.shrunk, self.original, self.steps
+        )
+    }
+}
+
+/// Checks properties on a number of random inputs.
+#[derive(Debug, Clone, Copy)]
+pub struct Checker {
+    cases: usize,
+    seed: u64,
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Checker {
+            cases: DEFAULT_CASES,
+            seed: DEFAULT_SEED,
+        }
+    }
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        Checker::default()
+    }
+
+    pub fn with_cases(self, cases: usize) -> Self {
+        Checker { cases, ..self }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Checker { seed, ..self }
+    }
+
+    /// Checks the property on generated inputs, shrinking the first one which
+    /// breaks it with the smaller candidates returned by `shrink`. A property
+    /// which panics is broken too.
+    pub fn check<T, G, S, P>(&self, generate: G, shrink: S, property: P) -> Result<(), Failure<T>>
+    where
+        T: Clone,
+        G: Fn(&mut Rng) -> T,
+        S: Fn(&T) -> Vec<T>,
+        P: Fn(&T) -> Result<(), String>,
+    {
+        let mut rng = Rng::new(self.seed);
+        for case in 1..=self.cases {
+            let input = generate(&mut rng);
+            if let Err(message) = holds(&property, &input) {
+                let (shrunk, steps, message) = minimize(input.clone(), message, shrink, property);
+                return Err(Failure {
+                    case,
+                    seed: self.seed,
+                    original: input,
+                    shrunk,
+                    steps,
+                    message,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the property, panicking with the shrunk input if it fails.
+    pub fn assert<T, G, S, P>(&self, generate: G, shrink: S, property: P)
+    where
+        T: Clone + Debug,
+        G: Fn(&mut Rng) -> T,
+        S: Fn(&T) -> Vec<T>,
+        P: Fn(&T) -> Result<(), String>,
+    {
+        if let Err(failure) = self.check(generate, shrink, property) {
+            panic!("{}", failure);
+        }
+    }
+}
+
+/// Replaces the input by the first candidate which still breaks the property,
+/// until none does.
+fn minimize<T, S, P>(
+    mut input: T,
+    mut message: String,
+    shrink: S,
+    property: P,
+) -> (T, usize, String)
+where
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Result<(), String>,
+{
+    let mut steps = 0;
+    'shrinking: while steps < MAX_SHRINK_STEPS {
+        for candidate in shrink(&input) {
+            if let Err(m) = holds(&property, &candidate) {
+                input = candidate;
+                message = m;
+                steps += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    (input, steps, message)
+}
+
+/// Checks the property on an input, turning a panic into an error.
+fn holds<T, P>(property: &P, input: &T) -> Result<(), String>
+where
+    P: Fn(&T) -> Result<(), String>,
+{
+    catch_panic(|| property(input)).unwrap_or_else(|message| Err(format!("panicked: {}", message)))
+}
+
+/// Succeeds if both implementations give the same result.
+pub fn agree<T: PartialEq + Debug>(naive: T, optimized: T) -> Result<(), String> {
+    match naive == optimized {
+        true => Ok(()),
+        false => Err(format!("expected {:?}, got {:?}", naive, optimized)),
+    }
+}
+
+/// Returns smaller numbers, the smallest first.
+pub fn shrink_number(n: usize) -> Vec<usize> {
+    let mut candidates = vec![0, n / 2, n.saturating_sub(1)];
+    candidates.dedup();
+    candidates.retain(|&c| c < n);
+    candidates
+}
+
+/// Returns shorter lists: without their first or second half, then without
+/// each of their items.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        candidates.push(items[half..].to_vec());
+        candidates.push(items[..half].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut candidate = items.to_vec();
+        candidate.remove(i);
+        candidates.push(candidate);
+    }
+    candidates
+}
+
+/// Returns the lists with one of their items shrunk.
+pub fn shrink_items<T: Clone>(items: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for smaller in shrink(item) {
+            let mut candidate = items.to_vec();
+            candidate[i] = smaller;
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn numbers(rng: &mut Rng) -> Vec<usize> {
+        (0..rng.below(20)).map(|_| rng.below(100)).collect()
+    }
+
+    fn shrink_numbers(numbers: &[usize]) -> Vec<Vec<usize>> {
+        let mut candidates = shrink_vec(numbers);
+        candidates.extend(shrink_items(numbers, |&n| shrink_number(n)));
+        candidates
+    }
+
+    fn all_small(numbers: &[usize]) -> Result<(), String> {
+        match numbers.iter().all(|&n| n < 10) {
+            true => Ok(()),
+            false => Err("too large".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let checker = Checker::new();
+        let shrink_numbers = |numbers: &Vec<usize>| shrink_numbers(numbers);
+        let all_small = |numbers: &Vec<usize>| all_small(numbers);
+        assert_eq!(
+            checker.check(numbers, shrink_numbers, |n| agree(n.len(), n.len())),
+            Ok(())
+        );
+
+        let failure = checker
+            .check(numbers, shrink_numbers, all_small)
+            .unwrap_err();
+        assert_eq!(failure.shrunk, vec![10]);
+        assert_eq!(failure.message, "too large");
+        assert!(failure.steps > 0);
+        assert_ne!(failure.original, failure.shrunk);
+        // The same seed finds the same failure.
+        assert_eq!(
+            Some(failure),
+            checker.check(numbers, shrink_numbers, all_small).err()
+        );
+
+        let failure = checker
+            .check(numbers, shrink_numbers, |numbers| {
+                assert!(numbers.iter().all(|&n| n < 10), "too large");
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!(failure.shrunk, vec![10]);
+        assert_eq!(failure.message, "panicked: too large");
+        assert!(failure.steps > 0);
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(shrink_number(0), Vec::<usize>::new());
+        assert_eq!(shrink_number(1), vec![0]);
+        assert_eq!(shrink_number(10), vec![0, 5, 9]);
+        assert_eq!(
+            shrink_vec(&[1, 2, 3]),
+            vec![vec![2, 3], vec![1], vec![2, 3], vec![1, 3], vec![1, 2]]
+        );
+        assert_eq!(
+            shrink_items(&[1, 2], |&n| shrink_number(n)),
+            vec![vec![0, 2], vec![1, 0], vec![1, 1]]
+        );
+    }
+}
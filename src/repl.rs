@@ -0,0 +1,380 @@
+//! An interactive shell to explore the puzzle inputs and the solvers.
+//!
+//! Besides running the solvers, days expose probes: functions computing an
+//! intermediate result from the input for a tunable parameter, like the
+//! number of simulated days of day 6, and printing it.
+
+use crate::runner::{catch_panic, run_solver, Outcome};
+use crate::{asset_path_in, AocError, Part, Registry};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// The file the commands of the shell are saved to, in the assets directory.
+pub const DEFAULT_REPL_HISTORY: &str = ".aoc2021_repl_history";
+
+const HELP: &str = "Commands:
+  load <dayNN> [PATH]   Load the input of a day from the assets, or from PATH
+  input                 Print the loaded input
+  run [a|b]             Run the solvers of the day on the input
+  probes                List the probes of the day and their parameters
+  set <PARAM> <VALUE>   Set a parameter of the probes of the day
+  show <PROBE> [VALUE]  Run a probe, with VALUE as its parameter if given
+  history               List the previous commands
+  !N                    Run the Nth command of the history again
+  help                  Print this message
+  quit                  Leave";
+
+/// Computes and formats an intermediate result of a day.
+pub struct Probe {
+    pub day: u8,
+    pub name: &'static str,
+    pub help: &'static str,
+    /// The name of the parameter and its default value, e.g. `("days", 80)`.
+    pub param: (&'static str, usize),
+    pub run: fn(&str, usize) -> Result<String, AocError>,
+}
+
+/// Collection of the probes, in registration order.
+#[derive(Default)]
+pub struct Probes {
+    probes: Vec<Probe>,
+}
+
+impl Probes {
+    pub fn new() -> Self {
+        Probes::default()
+    }
+
+    pub fn register(&mut self, probe: Probe) {
+        self.probes.push(probe);
+    }
+
+    pub fn day(&self, day: u8) -> impl Iterator<Item = &Probe> {
+        self.probes.iter().filter(move |p| p.day == day)
+    }
+
+    pub fn get(&self, day: u8, name: &str) -> Option<&Probe> {
+        self.day(day).find(|p| p.name == name)
+    }
+}
+
+/// What to do after a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// Print the output, then read the next command.
+    Continue(String),
+    Quit,
+}
+
+/// The state of the shell.
+pub struct Repl<'a> {
+    registry: &'a Registry,
+    probes: &'a Probes,
+    assets_dir: PathBuf,
+    day: Option<u8>,
+    input: Option<String>,
+    params: HashMap<(u8, &'static str), usize>,
+    history: Vec<String>,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new<P: AsRef<Path>>(registry: &'a Registry, probes: &'a Probes, assets_dir: P) -> Self {
+        Repl {
+            registry,
+            probes,
+            assets_dir: assets_dir.as_ref().to_path_buf(),
+            day: None,
+            input: None,
+            params: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Sets the commands of previous sessions, `!N` can run again.
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.history = history;
+        self
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Runs a command, recording it in the history.
+    pub fn execute(&mut self, line: &str) -> Step {
+        let line = line.trim();
+        let line = match line.strip_prefix('!') {
+            Some(n) => match n
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| self.history.get(n.wrapping_sub(1)))
+            {
+                Some(previous) => previous.clone(),
+                None => return Step::Continue(format!("error: no command #{} in the history", n)),
+            },
+            None => line.to_string(),
+        };
+        if line.is_empty() {
+            return Step::Continue(String::new());
+        }
+        if line != "history" {
+            self.history.push(line.clone());
+        }
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let output = match words.as_slice() {
+            ["quit"] | ["exit"] => return Step::Quit,
+            ["help"] => Ok(HELP.to_string()),
+            ["load", day] => self.load(day, None),
+            ["load", day, path] => self.load(day, Some(Path::new(path))),
+            ["input"] => self.input().map(|(_, input)| input.to_string()),
+            ["run"] => self.run(&Part::all()),
+            ["run", part] => part.parse().and_then(|part| self.run(&[part])),
+            ["probes"] => self.list_probes(),
+            ["set", param, value] => self.set(param, value),
+            ["show", probe] => self.show(probe, None),
+            ["show", probe, value] => self.show(probe, Some(value)),
+            ["history"] => Ok(self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, command)| format!("{:>4}  {}", i + 1, command))
+                .collect::<Vec<String>>()
+                .join("\n")),
+            _ => Err(format!("unknown command `{}`, try `help`", line)),
+        };
+        Step::Continue(output.unwrap_or_else(|e| format!("error: {}", e)))
+    }
+
+    fn load(&mut self, day: &str, path: Option<&Path>) -> Result<String, String> {
+        let day = day
+            .strip_prefix("day")
+            .unwrap_or(day)
+            .parse::<u8>()
+            .map_err(|_| format!("invalid day `{}`", day))?;
+        let path = path.map_or_else(|| asset_path_in(&self.assets_dir, day), Path::to_path_buf);
+        let input = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        let summary = format!(
+            "Loaded {} lines from {} for day {:02}",
+            input.lines().count(),
+            path.display(),
+            day
+        );
+        self.day = Some(day);
+        self.input = Some(input);
+        Ok(summary)
+    }
+
+    fn input(&self) -> Result<(u8, &str), String> {
+        match (self.day, &self.input) {
+            (Some(day), Some(input)) => Ok((day, input)),
+            _ => Err("no input loaded, use `load <dayNN>`".to_string()),
+        }
+    }
+
+    fn run(&self, parts: &[Part]) -> Result<String, String> {
+        let (day, input) = self.input()?;
+        let lines = parts
+            .iter()
+            .filter_map(|&part| self.registry.get(day, part))
+            .map(|solver| match run_solver(solver, input) {
+                Outcome::Solved(run) => format!(
+                    "{}  {}  (parse {:?}, solve {:?})",
+                    solver.id(),
+                    run.answer,
+                    run.parse_time,
+                    run.solve_time
+                ),
+                outcome => format!("{}  {}", solver.id(), outcome),
+            })
+            .collect::<Vec<String>>();
+        match lines.is_empty() {
+            true => Err(format!("day {:02} has no such solver", day)),
+            false => Ok(lines.join("\n")),
+        }
+    }
+
+    fn param(&self, probe: &Probe) -> usize {
+        let (name, default) = probe.param;
+        self.params
+            .get(&(probe.day, name))
+            .copied()
+            .unwrap_or(default)
+    }
+
+    fn list_probes(&self) -> Result<String, String> {
+        let (day, _) = self.input()?;
+        let lines = self
+            .probes
+            .day(day)
+            .map(|probe| {
+                format!(
+                    "{}  {} = {}  {}",
+                    probe.name,
+                    probe.param.0,
+                    self.param(probe),
+                    probe.help
+                )
+            })
+            .collect::<Vec<String>>();
+        match lines.is_empty() {
+            true => Ok(format!("day {:02} has no probes", day)),
+            false => Ok(lines.join("\n")),
+        }
+    }
+
+    fn set(&mut self, param: &str, value: &str) -> Result<String, String> {
+        let (day, _) = self.input()?;
+        let value = parse_value(value)?;
+        let name = self
+            .probes
+            .day(day)
+            .map(|probe| probe.param.0)
+            .find(|&name| name == param)
+            .ok_or_else(|| format!("day {:02} has no parameter `{}`", day, param))?;
+        self.params.insert((day, name), value);
+        Ok(format!("{} = {}", name, value))
+    }
+
+    fn show(&self, name: &str, value: Option<&str>) -> Result<String, String> {
+        let (day, input) = self.input()?;
+        let probe = self
+            .probes
+            .get(day, name)
+            .ok_or_else(|| format!("day {:02} has no probe `{}`", day, name))?;
+        let value = match value {
+            Some(value) => parse_value(value)?,
+            None => self.param(probe),
+        };
+        match catch_panic(|| (probe.run)(input, value)) {
+            Ok(output) => output.map_err(|e| e.to_string()),
+            Err(message) => Err(format!("panicked: {}", message)),
+        }
+    }
+}
+
+fn parse_value(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{}`: expected a positive number", value))
+}
+
+/// Runs the shell until `quit` or the end of the input, appending the
+/// commands to the history file if any.
+pub fn run<R: BufRead, W: Write>(
+    mut repl: Repl,
+    input: R,
+    mut output: W,
+    history_path: Option<&Path>,
+) -> io::Result<()> {
+    let mut history = match history_path {
+        Some(path) => Some(OpenOptions::new().create(true).append(true).open(path)?),
+        None => None,
+    };
+    write!(output, "> ")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let recorded = repl.history().len();
+        let step = repl.execute(&line);
+        if let Some(file) = history.as_mut() {
+            for command in &repl.history()[recorded..] {
+                writeln!(file, "{}", command)?;
+            }
+        }
+        match step {
+            Step::Quit => return Ok(()),
+            Step::Continue(text) if text.is_empty() => (),
+            Step::Continue(text) => writeln!(output, "{}", text)?,
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
+
+/// Loads the commands of previous sessions, if any.
+pub fn load_history<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content.lines().map(String::from).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{probes, registry};
+
+    fn output(step: Step) -> String {
+        match step {
+            Step::Continue(text) => text,
+            Step::Quit => panic!("unexpected quit"),
+        }
+    }
+
+    #[test]
+    fn test_repl() {
+        let registry = registry(2021);
+        let probes = probes(2021);
+        let mut repl = Repl::new(&registry, &probes, "assets/2021");
+        assert!(output(repl.execute("run")).starts_with("error: no input loaded"));
+        assert_eq!(
+            output(repl.execute("load day06 examples/2021/day06/example.txt")),
+            "Loaded 1 lines from examples/2021/day06/example.txt for day 06"
+        );
+        assert_eq!(output(repl.execute("input")), "3,4,3,1,2\n");
+        assert!(output(repl.execute("run a")).starts_with("day06a  5934  (parse "));
+        assert_eq!(output(repl.execute("show count 18")), "26");
+        assert_eq!(output(repl.execute("show count")), "5934");
+        assert_eq!(output(repl.execute("set days 256")), "days = 256");
+        assert_eq!(output(repl.execute("show count")), "26984457539");
+        assert_eq!(output(repl.execute("!7")), "days = 256");
+        assert_eq!(
+            output(repl.execute("show count 600")),
+            "error: too many lanternfish to count after 600 days"
+        );
+        assert!(output(repl.execute("set cycles 2")).starts_with("error: day 06 has no parameter"));
+        assert!(output(repl.execute("show nothing")).starts_with("error:"));
+        assert!(output(repl.execute("!99")).starts_with("error: no command #99"));
+        assert!(output(repl.execute("frobnicate")).starts_with("error: unknown command"));
+        assert!(output(repl.execute("history")).starts_with("   1  run\n   2  load day06"));
+        assert_eq!(repl.execute("quit"), Step::Quit);
+    }
+
+    #[test]
+    fn test_show_panic() {
+        let registry = registry(2021);
+        let mut probes = Probes::new();
+        probes.register(Probe {
+            day: 1,
+            name: "boom",
+            help: "Panic",
+            param: ("n", 0),
+            run: |_, _| panic!("boom"),
+        });
+        let mut repl = Repl::new(&registry, &probes, "assets/2021");
+        output(repl.execute("load day01 examples/2021/day01/example.txt"));
+        assert_eq!(output(repl.execute("show boom")), "error: panicked: boom");
+        assert_eq!(output(repl.execute("input")).lines().count(), 10);
+    }
+
+    #[test]
+    fn test_run() {
+        let registry = registry(2021);
+        let probes = probes(2021);
+        let repl =
+            Repl::new(&registry, &probes, "examples/2021/day11").with_history(vec!["help".into()]);
+        let commands = "load day11 examples/2021/day11/example.txt\nshow map 1\n!1\nset cycles 10\nshow flashes\nquit\nrun\n";
+        let mut output = Vec::new();
+        run(repl, commands.as_bytes(), &mut output, None).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("> 6594254334\n3856965822\n"));
+        assert!(output.contains("> Commands:\n"));
+        assert!(output.contains("> cycles = 10\n> 204\n"));
+        assert!(!output.contains("day11a"));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{AocError, Part, Run, Solver};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Run),
+    /// The input of the day could not be loaded.
+    MissingInput(String),
+    /// The input could not be parsed.
+    Invalid(AocError),
+    /// The solver panicked, with the panic message.
+    Panicked(String),
+}
+
+/// The outcome of running one solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome, Outcome::Solved(_))
+    }
+}
+
+/// Displays the answer, or why there is none.
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved(run) => write!(f, "{}", run.answer),
+            Outcome::MissingInput(e) => write!(f, "missing input: {}", e),
+            Outcome::Invalid(e) => write!(f, "invalid input: {}", e),
+            Outcome::Panicked(e) => write!(f, "panicked: {}", e),
+        }
+    }
+}
+
+/// Loads the input of each day at most once.
+pub struct InputCache<F> {
+    read_input: F,
+    inputs: HashMap<u8, Result<String, String>>,
+}
+
+impl<F> InputCache<F>
+where
+    F: FnMut(u8) -> io::Result<String>,
+{
+    pub fn new(read_input: F) -> Self {
+        InputCache {
+            read_input,
+            inputs: HashMap::new(),
+        }
+    }
+
+    /// Returns the input of a day, or why it could not be loaded.
+    pub fn get(&mut self, day: u8) -> Result<&str, &str> {
+        let read_input = &mut self.read_input;
+        self.inputs
+            .entry(day)
+            .or_insert_with(|| read_input(day).map_err(|e| e.to_string()))
+            .as_deref()
+            .map_err(String::as_str)
+    }
+}
+
+/// Runs a solver, catching its panics.
+pub fn run_solver(solver: &dyn Solver, input: &str) -> Outcome {
+    match catch_panic(|| solver.run(input)) {
+        Ok(Ok(run)) => Outcome::Solved(run),
+        Ok(Err(e)) => Outcome::Invalid(e),
+        Err(message) => Outcome::Panicked(message),
+    }
+}
+
+/// Runs a function, returning the message of its panic if it panics.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        }
+    })
+}
+
+/// Runs the solvers one after the other.
+///
+/// The input of each day is only loaded once, by `read_input`.
+pub fn run_all<'a, I, F>(solvers: I, read_input: F) -> Vec<Report>
+where
+    I: IntoIterator<Item = &'a dyn Solver>,
+    F: FnMut(u8) -> io::Result<String>,
+{
+    let mut inputs = InputCache::new(read_input);
+    solvers
+        .into_iter()
+        .map(|solver| report(solver, inputs.get(solver.day())))
+        .collect()
+}
+
+/// Runs the solvers on up to `jobs` threads.
+///
+/// The inputs are all loaded beforehand, by `read_input`, so only the solvers
+/// run concurrently. The reports are in the same order as the solvers.
+pub fn run_parallel<'a, I, F>(solvers: I, read_input: F, jobs: usize) -> Vec<Report>
+where
+    I: IntoIterator<Item = &'a dyn Solver>,
+    F: FnMut(u8) -> io::Result<String>,
+{
+    if jobs <= 1 {
+        return run_all(solvers, read_input);
+    }
+    let mut inputs = InputCache::new(read_input);
+    let tasks = solvers
+        .into_iter()
+        .map(|solver| {
+            let input = inputs.get(solver.day());
+            (solver, input.map(str::to_string).map_err(str::to_string))
+        })
+        .collect::<Vec<(&dyn Solver, Result<String, String>)>>();
+
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(vec![None; tasks.len()]);
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((solver, input)) = tasks.get(i) else {
+                    break;
+                };
+                let report = report(*solver, input.as_deref().map_err(String::as_str));
+                reports.lock().unwrap()[i] = Some(report);
+            });
+        }
+    });
+    reports
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|report| report.expect("every solver ran"))
+        .collect()
+}
+
+fn report(solver: &dyn Solver, input: Result<&str, &str>) -> Report {
+    let outcome = match input {
+        Ok(input) => run_solver(solver, input),
+        Err(e) => Outcome::MissingInput(e.to_string()),
+    };
+    Report {
+        day: solver.day(),
+        part: solver.part(),
+        outcome,
+    }
+}
+
+/// Formats the reports as a table.
+pub struct Table<'a>(pub &'a [Report]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for report in self.0 {
+            let (parse, solve) = match &report.outcome {
+                Outcome::Solved(run) => (
+                    format_duration(run.parse_time),
+                    format_duration(run.solve_time),
+                ),
+                _ => ("-".into(), "-".into()),
+            };
+            // Multi-line answers (like day 13) continue on the following rows.
+            for (i, line) in report.outcome.to_string().lines().enumerate() {
+                if i == 0 {
+                    rows.push(vec![
+                        format!("{:02}", report.day),
+                        report.part.to_string(),
+                        line.to_string(),
+                        parse.clone(),
+                        solve.clone(),
+                    ]);
+                } else {
+                    rows.push(vec![
+                        String::new(),
+                        String::new(),
+                        line.to_string(),
+                        String::new(),
+                        String::new(),
+                    ]);
+                }
+            }
+        }
+        write_table(f, &["Day", "Part", "Answer", "Parse", "Solve"], &rows, 3)
+    }
+}
+
+/// Writes rows as aligned columns, after a header.
+///
+/// The first `left` columns are aligned to the left, the others to the right.
+pub(crate) fn write_table(
+    f: &mut fmt::Formatter,
+    header: &[&str],
+    rows: &[Vec<String>],
+    left: usize,
+) -> fmt::Result {
+    let header = header
+        .iter()
+        .map(|h| h.to_string())
+        .collect::<Vec<String>>();
+    let mut widths = header.iter().map(String::len).collect::<Vec<usize>>();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(i, (cell, &width))| match i < left {
+                true => format!("{:<width$}", cell, width = width),
+                false => format!("{:>width$}", cell, width = width),
+            })
+            .collect::<Vec<String>>()
+            .join("  ");
+        writeln!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3?}", duration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{answer, boom, length};
+    use crate::{Answer, Registry, Solution};
+
+    #[test]
+    fn test_run_all() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(1, Part::A, "Length", length, answer));
+        registry.register(Solution::new(1, Part::B, "Boom", length, boom));
+        registry.register(Solution::new(2, Part::A, "Length", length, answer));
+        registry.register(Solution::new(3, Part::A, "Length", length, answer));
+        let reports = run_all(registry.iter(), |day| match day {
+            1 => Ok("abc".to_string()),
+            3 => Ok("".to_string()),
+            _ => Err(io::Error::new(io::ErrorKind::NotFound, "not found")),
+        });
+
+        assert_eq!(reports.len(), 4);
+        match &reports[0].outcome {
+            Outcome::Solved(run) => assert_eq!(run.answer, Answer::Number(3)),
+            outcome => panic!("unexpected outcome: {:?}", outcome),
+        }
+        assert_eq!(reports[1].outcome, Outcome::Panicked("boom".into()));
+        assert_eq!(
+            reports[2].outcome,
+            Outcome::MissingInput("not found".into())
+        );
+        assert_eq!(
+            reports[3].outcome,
+            Outcome::Invalid(AocError::new("empty input").with_day(3))
+        );
+
+        let table = Table(&reports).to_string();
+        assert!(table.starts_with("Day  Part  Answer"));
+        assert_eq!(table.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let registry = crate::registry(2021);
+        let read_input = |day| crate::read_asset(2021, day).map_err(io::Error::other);
+        let sequential = run_all(registry.iter(), read_input);
+        let parallel = run_parallel(registry.iter(), read_input, 4);
+        assert_eq!(parallel.len(), sequential.len());
+        for (a, b) in sequential.iter().zip(parallel.iter()) {
+            assert_eq!((a.day, a.part), (b.day, b.part));
+            assert_eq!(a.outcome.to_string(), b.outcome.to_string());
+        }
+    }
+}
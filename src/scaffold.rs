@@ -0,0 +1,322 @@
+use crate::year_dir;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The expected answers of a new example, until the puzzle is pasted in.
+const EXAMPLE_ANSWERS: &str =
+    "# The answers of example.txt, as `<part> <answer>` lines, e.g. `a 7`.\n";
+
+/// The template of a day module, where `__DAY__` stands for the day number
+/// and `__DD__` for the same number padded to two digits.
+const TEMPLATE: &str = r#"use super::read_asset;
+use crate::{Answer, AocError, Part, Registry, Solution};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(__DAY__, Part::A, "Day __DD__", parse, part_a));
+    registry.register(Solution::new(__DAY__, Part::B, "Day __DD__", parse, part_b));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_b)
+}
+
+pub fn day__DD__a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(__DAY__)?)?.to_string())
+}
+
+pub fn day__DD__b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(__DAY__)?)?.to_string())
+}
+
+// Refuses every input until the puzzle is solved, e.g. parse the lines with
+// `crate::parse_lines(input, |line| Ok(line.to_string()))`.
+fn parse(_input: &str) -> Result<Vec<String>, AocError> {
+    Err(AocError::new("not solved yet"))
+}
+
+fn part_a(lines: Vec<String>) -> Answer {
+    lines.len().into()
+}
+
+fn part_b(lines: Vec<String>) -> Answer {
+    lines.len().into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "";
+
+    #[test]
+    #[ignore = "the example of the puzzle is missing"]
+    fn test_day__DD___parta_sample() {
+        assert_eq!(solve_a(RAW_INPUT), Ok(Answer::Number(0)));
+    }
+
+    #[test]
+    #[ignore = "the example of the puzzle is missing"]
+    fn test_day__DD___partb_sample() {
+        assert_eq!(solve_b(RAW_INPUT), Ok(Answer::Number(0)));
+    }
+}
+"#;
+
+/// Where the files of a new day of a year go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// The root of the crate, holding `src/`.
+    pub root: PathBuf,
+    pub year: u16,
+    /// The assets directory of the year.
+    pub assets_dir: PathBuf,
+    /// The examples directory of the year.
+    pub examples_dir: PathBuf,
+}
+
+impl Layout {
+    /// Returns the layout of a year in the crate at `root`, with the default
+    /// `assets` and `examples` directories.
+    pub fn new<P: AsRef<Path>>(root: P, year: u16) -> Self {
+        let root = root.as_ref().to_path_buf();
+        Layout {
+            assets_dir: year_dir(root.join("assets"), year),
+            examples_dir: year_dir(root.join(crate::examples::DEFAULT_EXAMPLES), year),
+            root,
+            year,
+        }
+    }
+
+    fn year_module_dir(&self) -> PathBuf {
+        self.root.join("src").join(format!("y{}", self.year))
+    }
+
+    fn year_module_path(&self) -> PathBuf {
+        self.year_module_dir().join("mod.rs")
+    }
+
+    fn module_path(&self, day: u8) -> PathBuf {
+        self.year_module_dir().join(format!("day{:02}.rs", day))
+    }
+
+    fn asset_path(&self, day: u8) -> PathBuf {
+        crate::asset_path_in(&self.assets_dir, day)
+    }
+
+    fn example_path(&self, day: u8) -> PathBuf {
+        self.examples_dir
+            .join(format!("day{:02}", day))
+            .join("example.txt")
+    }
+
+    fn example_answers_path(&self, day: u8) -> PathBuf {
+        self.example_path(day).with_extension("answers")
+    }
+}
+
+/// Generates the module of a new day and registers it.
+///
+/// Creates `src/yYYYY/dayNN.rs` from a template, declares and registers it in
+/// `src/yYYYY/mod.rs`, and creates an empty input, and an empty example with
+/// its answers file, to paste the puzzle into. The module refuses every input
+/// until the puzzle is solved. Nothing is written if any of these files
+/// already exists.
+///
+/// The module of the year must exist, see `YEARS`.
+///
+/// Returns the paths of the files created or updated.
+pub fn new_day(layout: &Layout, day: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid_input(format!(
+            "invalid day {}: expected 1 to 25",
+            day
+        )));
+    }
+    let mod_path = layout.year_module_path();
+    let year_module = fs::read_to_string(&mod_path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("could not read {}: {}", mod_path.display(), e),
+        )
+    })?;
+    let created = [
+        layout.module_path(day),
+        layout.asset_path(day),
+        layout.example_path(day),
+        layout.example_answers_path(day),
+    ];
+    if let Some(existing) = created.iter().find(|path| path.exists()) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", existing.display()),
+        ));
+    }
+
+    // Update the year module in memory first, so it is left untouched on
+    // error.
+    let year_module = insert_sorted(
+        &year_module,
+        day,
+        |line| parse_day_number(line.strip_prefix("pub mod day")?.strip_suffix(';')?),
+        &format!("pub mod day{:02};", day),
+    )?;
+    let year_module = insert_sorted(
+        &year_module,
+        day,
+        |line| {
+            let line = line.strip_prefix("day")?;
+            parse_day_number(line.strip_suffix("::register(registry);")?)
+        },
+        &format!("day{:02}::register(registry);", day),
+    )?;
+
+    let module = TEMPLATE
+        .replace("__DD__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string());
+    fs::write(&created[0], module)?;
+    for path in created[1..].iter() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = match path == &created[3] {
+            true => EXAMPLE_ANSWERS,
+            false => "",
+        };
+        fs::write(path, content)?;
+    }
+    fs::write(&mod_path, year_module)?;
+
+    let mut paths = created.to_vec();
+    paths.push(mod_path);
+    Ok(paths)
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn parse_day_number(digits: &str) -> Option<u8> {
+    match digits.len() == 2 && digits.chars().all(|c| c.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// Inserts `new_line` among the lines for which `day_of` returns a day, so
+/// that they stay sorted by day, with the same indentation.
+fn insert_sorted<F>(content: &str, day: u8, day_of: F, new_line: &str) -> io::Result<String>
+where
+    F: Fn(&str) -> Option<u8>,
+{
+    let lines = content.lines().collect::<Vec<&str>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line.trim()).map(|d| (i, d)))
+        .collect::<Vec<(usize, u8)>>();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("`{}` is already there", new_line),
+        ));
+    }
+    let (neighbor, position) = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => (i, i + 1),
+        None => match days.first() {
+            Some(&(i, _)) => (i, i),
+            None => {
+                return Err(invalid_input(format!(
+                    "could not find where to add `{}`",
+                    new_line
+                )))
+            }
+        },
+    };
+    let indentation =
+        &lines[neighbor][..lines[neighbor].len() - lines[neighbor].trim_start().len()];
+    let mut updated = lines[..position].to_vec();
+    let new_line = format!("{}{}", indentation, new_line);
+    updated.push(&new_line);
+    updated.extend_from_slice(&lines[position..]);
+    let mut updated = updated.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const YEAR_MODULE: &str = "use crate::Registry;
+
+pub mod day01;
+pub mod day03;
+
+pub fn register(registry: &mut Registry) {
+    day01::register(registry);
+    day03::register(registry);
+}
+";
+
+    #[test]
+    fn test_insert_sorted() {
+        let day_of =
+            |line: &str| parse_day_number(line.strip_prefix("pub mod day")?.strip_suffix(';')?);
+        let module = insert_sorted(YEAR_MODULE, 2, day_of, "pub mod day02;").unwrap();
+        assert!(module.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        let module = insert_sorted(YEAR_MODULE, 25, day_of, "pub mod day25;").unwrap();
+        assert!(module.contains("pub mod day03;\npub mod day25;\n\npub fn"));
+        assert!(insert_sorted(YEAR_MODULE, 3, day_of, "pub mod day03;").is_err());
+        assert!(insert_sorted("", 3, day_of, "pub mod day03;").is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc2021_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/y2021")).unwrap();
+        fs::write(root.join("src/y2021/mod.rs"), YEAR_MODULE).unwrap();
+        let layout = Layout::new(&root, 2021);
+        assert!(new_day(&Layout::new(&root, 2015), 2).is_err());
+
+        let paths = new_day(&layout, 2).unwrap();
+        assert_eq!(paths.len(), 5);
+        let module = fs::read_to_string(root.join("src/y2021/day02.rs")).unwrap();
+        assert!(module.contains("Solution::new(2, Part::A, \"Day 02\", parse, part_a)"));
+        assert!(module.contains("pub fn day02b()"));
+        assert!(module.contains("fn test_day02_partb_sample()"));
+        let year_module = fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap();
+        assert!(year_module.contains("pub mod day02;\npub mod day03;"));
+        assert!(year_module.contains("    day02::register(registry);\n    day03::register"));
+        assert_eq!(
+            fs::read_to_string(root.join("assets/2021/day02.txt")).unwrap(),
+            ""
+        );
+        assert!(root.join("examples/2021/day02/example.txt").exists());
+        let examples = crate::examples::load_examples(root.join("examples/2021")).unwrap();
+        assert!(examples.is_empty());
+        fs::write(root.join("examples/2021/day02/example.txt"), "1\n").unwrap();
+        let examples = crate::examples::load_examples(root.join("examples/2021")).unwrap();
+        assert!(examples[0].expected.is_empty());
+
+        // Nothing is overwritten, not even partially.
+        fs::remove_file(root.join("src/y2021/day02.rs")).unwrap();
+        let error = new_day(&layout, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(!root.join("src/y2021/day02.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap(),
+            year_module
+        );
+        assert!(new_day(&layout, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,505 @@
+//! A small HTTP server exposing the solvers.
+//!
+//! - `GET /solvers` lists the registered solvers.
+//! - `POST /day/NN/part/a` solves a puzzle, taking its input as the request
+//!   body, and returns the answer with the parse and solve times in
+//!   nanoseconds.
+//!
+//! Every response is a JSON object, with an `error` field on failure.
+
+use crate::runner::{run_solver, Outcome};
+use crate::{warn, Answer, Part, Registry};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:2021";
+
+/// The largest request line and headers accepted, in bytes.
+const MAX_HEAD: u64 = 8 * 1024;
+
+/// How long to wait after failing to accept a connection.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// How much a request may ask of the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The largest body accepted, in bytes.
+    pub max_body: usize,
+    /// How long to wait for a whole request to be received, and then solved.
+    pub timeout: Duration,
+    /// The most connections handled at once, counting those whose solver
+    /// timed out but is still running. Others are refused.
+    pub max_connections: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_body: 1024 * 1024,
+            timeout: Duration::from_secs(10),
+            max_connections: 32,
+        }
+    }
+}
+
+pub struct Server {
+    listener: TcpListener,
+    registry: Arc<Registry>,
+    limits: Limits,
+    connections: Arc<AtomicUsize>,
+}
+
+/// A connection being handled, released once its thread and the thread of
+/// its solver are both done.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    /// Takes a slot, unless all of them are taken.
+    fn take(connections: &Arc<AtomicUsize>, max: usize) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                (used < max).then_some(used + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(connections)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(
+        address: A,
+        registry: Arc<Registry>,
+        limits: Limits,
+    ) -> io::Result<Self> {
+        Ok(Server {
+            listener: TcpListener::bind(address)?,
+            registry,
+            limits,
+            connections: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serves requests forever, each connection on its own thread.
+    ///
+    /// Failing to accept a connection, e.g. when running out of file
+    /// descriptors, is reported and does not stop the server.
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!("could not accept a connection: {}", e);
+                    // Give the server a chance to release what it ran out of.
+                    thread::sleep(ACCEPT_BACKOFF);
+                    continue;
+                }
+            };
+            let Some(slot) = Slot::take(&self.connections, self.limits.max_connections) else {
+                let _ = refuse(stream, self.limits.timeout);
+                continue;
+            };
+            let registry = Arc::clone(&self.registry);
+            let limits = self.limits;
+            thread::spawn(move || {
+                // The client hung up or timed out, there is no one to tell.
+                let _ = handle(stream, registry, limits, Arc::new(slot));
+            });
+        }
+        Ok(())
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Response {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+}
+
+/// Tells a client that the server is too busy, without waiting for its request.
+fn refuse(stream: TcpStream, timeout: Duration) -> io::Result<()> {
+    stream.set_write_timeout(Some(timeout))?;
+    write_response(
+        stream.try_clone()?,
+        &Response::error(503, "too many connections"),
+    )?;
+    stream.shutdown(Shutdown::Write)?;
+    // Closing with an unread request resets the connection, which may lose
+    // the response: discard what was received so far.
+    stream.set_nonblocking(true)?;
+    let _ = io::copy(&mut (&stream).take(64 * 1024), &mut io::sink());
+    Ok(())
+}
+
+/// Reads from a stream until a deadline, however slowly the data comes in.
+struct DeadlineReader {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "deadline reached"));
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+fn handle(
+    stream: TcpStream,
+    registry: Arc<Registry>,
+    limits: Limits,
+    slot: Arc<Slot>,
+) -> io::Result<()> {
+    stream.set_write_timeout(Some(limits.timeout))?;
+    let mut reader = BufReader::new(DeadlineReader {
+        stream: stream.try_clone()?,
+        deadline: Instant::now() + limits.timeout,
+    });
+    let response = match read_request(&mut reader, &limits) {
+        Ok(request) => route(&request, registry, &limits, slot),
+        Err(response) => response,
+    };
+    write_response(stream, &response)
+}
+
+fn read_request<R: BufRead>(reader: &mut R, limits: &Limits) -> Result<Request, Response> {
+    let read_error = |e: io::Error| match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+            Response::error(408, "timed out reading the request")
+        }
+        _ => Response::error(400, &format!("could not read the request: {}", e)),
+    };
+
+    let mut head = reader.take(MAX_HEAD);
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        head.read_line(&mut line).map_err(read_error)?;
+        if !line.ends_with('\n') {
+            return Err(Response::error(431, "request head too large or truncated"));
+        }
+        if line.trim_end().is_empty() {
+            break;
+        }
+        lines.push(line.trim_end().to_string());
+    }
+
+    let mut request_line = lines
+        .first()
+        .map(|l| l.split_whitespace())
+        .into_iter()
+        .flatten();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let content_length = lines[1..]
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .map(|(_, value)| value.trim().parse::<usize>())
+        .transpose()
+        .map_err(|_| Response::error(400, "invalid Content-Length"))?;
+    let body = match (method, content_length) {
+        (_, Some(length)) if length > limits.max_body => {
+            return Err(Response::error(
+                413,
+                &format!("the body is larger than {} bytes", limits.max_body),
+            ))
+        }
+        (_, Some(length)) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).map_err(read_error)?;
+            String::from_utf8(body).map_err(|_| Response::error(400, "the body is not UTF-8"))?
+        }
+        ("POST", None) => return Err(Response::error(411, "missing Content-Length")),
+        (_, None) => String::new(),
+    };
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body,
+    })
+}
+
+fn route(request: &Request, registry: Arc<Registry>, limits: &Limits, slot: Arc<Slot>) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<&str>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["solvers"]) => list(&registry),
+        ("POST", ["day", day, "part", part]) => {
+            let puzzle = day.parse::<u8>().ok().zip(part.parse::<Part>().ok());
+            match puzzle {
+                Some((day, part)) if registry.get(day, part).is_some() => solve(
+                    registry,
+                    day,
+                    part,
+                    request.body.clone(),
+                    limits.timeout,
+                    slot,
+                ),
+                _ => Response::error(404, "no such puzzle"),
+            }
+        }
+        (_, ["solvers"]) | (_, ["day", _, "part", _]) => Response::error(405, "method not allowed"),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn list(registry: &Registry) -> Response {
+    let solvers = registry
+        .iter()
+        .map(|solver| {
+            format!(
+                "{{\"day\":{},\"part\":\"{}\",\"name\":{}}}",
+                solver.day(),
+                solver.part(),
+                json_string(solver.name())
+            )
+        })
+        .collect::<Vec<String>>();
+    Response {
+        status: 200,
+        body: format!("{{\"solvers\":[{}]}}", solvers.join(",")),
+    }
+}
+
+/// Solves a puzzle on another thread, giving up after `timeout`.
+///
+/// A solver cannot be interrupted, so one which times out keeps running in
+/// the background until it is done, holding the slot of its connection.
+fn solve(
+    registry: Arc<Registry>,
+    day: u8,
+    part: Part,
+    input: String,
+    timeout: Duration,
+    slot: Arc<Slot>,
+) -> Response {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _slot = slot;
+        if let Some(solver) = registry.get(day, part) {
+            let _ = sender.send(run_solver(solver, &input));
+        }
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Outcome::Solved(run)) => {
+            let answer = match &run.answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(text) => json_string(text),
+            };
+            Response {
+                status: 200,
+                body: format!(
+                    "{{\"day\":{},\"part\":\"{}\",\"answer\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+                    day,
+                    part,
+                    answer,
+                    run.parse_time.as_nanos(),
+                    run.solve_time.as_nanos()
+                ),
+            }
+        }
+        Ok(Outcome::Invalid(e)) => Response::error(400, &format!("invalid input: {}", e)),
+        Ok(outcome) => Response::error(500, &outcome.to_string()),
+        Err(_) => Response::error(504, "timed out solving the puzzle"),
+    }
+}
+
+fn write_response(mut stream: TcpStream, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Quotes a string as JSON.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{double, number, sleep};
+    use crate::{http, Solution};
+
+    fn start(limits: Limits) -> String {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(1, Part::A, "Double \"it\"", number, double));
+        registry.register(Solution::new(1, Part::B, "Sleep", number, sleep));
+        let server = Server::bind("127.0.0.1:0", Arc::new(registry), limits).unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        thread::spawn(move || server.run());
+        url
+    }
+
+    #[test]
+    fn test_serve() {
+        let limits = Limits {
+            max_body: 16,
+            timeout: Duration::from_millis(200),
+            ..Limits::default()
+        };
+        let url = start(limits);
+        let get = |path: &str| http::get(&format!("{}{}", url, path), "").unwrap();
+        let post = |path: &str, body: &str| {
+            http::post_form(&format!("{}{}", url, path), "", body).unwrap()
+        };
+
+        let response = get("/solvers");
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.body,
+            "{\"solvers\":[{\"day\":1,\"part\":\"a\",\"name\":\"Double \\\"it\\\"\"},\
+             {\"day\":1,\"part\":\"b\",\"name\":\"Sleep\"}]}"
+        );
+
+        let response = post("/day/01/part/a", "21\n");
+        assert_eq!(response.status, 200);
+        assert!(response
+            .body
+            .starts_with("{\"day\":1,\"part\":\"a\",\"answer\":42,\"parse_ns\":"));
+        assert_eq!(post("/day/1/part/b", "10").status, 200);
+
+        let response = post("/day/01/part/a", "forty-two");
+        assert_eq!(response.status, 400);
+        assert_eq!(
+            response.body,
+            "{\"error\":\"invalid input: day 01: expected a number: `forty-two`\"}"
+        );
+        assert_eq!(post("/day/01/part/a", "12345678901234567").status, 413);
+        assert_eq!(post("/day/01/part/b", "500").status, 504);
+        assert_eq!(post("/day/02/part/a", "1").status, 404);
+        assert_eq!(get("/day/01/part/a").status, 405);
+        assert_eq!(get("/").status, 404);
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        let url = start(Limits {
+            max_body: 16,
+            timeout: Duration::from_millis(100),
+            ..Limits::default()
+        });
+        let mut stream = TcpStream::connect(url.trim_start_matches("http://")).unwrap();
+        stream
+            .write_all(b"POST /day/01/part/a HTTP/1.1\r\nContent-Length: 10\r\n\r\n12")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+
+        // Sending a byte now and then does not keep the connection open.
+        let mut stream = TcpStream::connect(url.trim_start_matches("http://")).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let started = Instant::now();
+        thread::spawn(move || {
+            for byte in b"POST /day/01/part/a HTTP/1.1\r\n".iter() {
+                thread::sleep(Duration::from_millis(30));
+                if writer.write_all(&[*byte]).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response);
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_max_connections() {
+        let url = start(Limits {
+            max_body: 16,
+            timeout: Duration::from_millis(100),
+            max_connections: 1,
+        });
+        // A refused client may see its connection reset before the response.
+        let get = || http::get(&format!("{}/solvers", url), "").map_or(503, |r| r.status);
+        let post = |body: &str| {
+            http::post_form(&format!("{}/day/01/part/b", url), "", body)
+                .unwrap()
+                .status
+        };
+        assert_eq!(get(), 200);
+        // The solver which timed out still holds the only connection.
+        assert_eq!(post("400"), 504);
+        assert_eq!(get(), 503);
+        thread::sleep(Duration::from_millis(500));
+        assert_eq!(get(), 200);
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("#.\n.#"), "\"#.\\n.#\"");
+        assert_eq!(json_string("a\"b\\c\u{1}"), "\"a\\\"b\\\\c\\u0001\"");
+    }
+}
@@ -0,0 +1,262 @@
+use crate::AocError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// The two halves of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::A, Part::B]
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("invalid part: `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// The answer of a puzzle.
+///
+/// Most puzzles expect a number, but some of them (like day 13) expect a
+/// few letters drawn on a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+/// Numbers too large for an `i64` are kept as text rather than wrapped.
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(item: $t) -> Self {
+                    match i64::try_from(item) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(item.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(item: String) -> Self {
+        Answer::Text(item)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(item: &str) -> Self {
+        Answer::Text(item.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// The outcome of a solver, with the time spent in each of its stages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// A solution to one part of a daily puzzle.
+pub trait Solver: Send + Sync {
+    fn day(&self) -> u8;
+    fn part(&self) -> Part;
+    fn name(&self) -> &str;
+
+    /// Parses the input and solves the puzzle, timing both stages.
+    fn run(&self, input: &str) -> Result<Run, AocError>;
+
+    fn solve(&self, input: &str) -> Result<Answer, AocError> {
+        self.run(input).map(|run| run.answer)
+    }
+
+    /// Identifier used on the command line, e.g. `day01a`.
+    fn id(&self) -> String {
+        format!("day{:02}{}", self.day(), self.part())
+    }
+}
+
+/// A solver made of a parsing function and a solving function.
+pub struct Solution<T> {
+    day: u8,
+    part: Part,
+    name: &'static str,
+    parse: fn(&str) -> Result<T, AocError>,
+    solve: fn(T) -> Answer,
+}
+
+impl<T> Solution<T> {
+    pub fn new(
+        day: u8,
+        part: Part,
+        name: &'static str,
+        parse: fn(&str) -> Result<T, AocError>,
+        solve: fn(T) -> Answer,
+    ) -> Self {
+        Solution {
+            day,
+            part,
+            name,
+            parse,
+            solve,
+        }
+    }
+}
+
+impl<T> Solver for Solution<T> {
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part(&self) -> Part {
+        self.part
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn run(&self, input: &str) -> Result<Run, AocError> {
+        let start = Instant::now();
+        let parsed = (self.parse)(input).map_err(|e| e.with_day(self.day))?;
+        let parse_time = start.elapsed();
+        let start = Instant::now();
+        let answer = (self.solve)(parsed);
+        let solve_time = start.elapsed();
+        Ok(Run {
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+}
+
+/// Collection of all the solvers, ordered by day and part.
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers a solver, replacing any solver previously registered for the
+    /// same day and part.
+    pub fn register<S: Solver + 'static>(&mut self, solver: S) {
+        let key = (solver.day(), solver.part());
+        match self
+            .solvers
+            .binary_search_by_key(&key, |s| (s.day(), s.part()))
+        {
+            Ok(i) => self.solvers[i] = Box::new(solver),
+            Err(i) => self.solvers.insert(i, Box::new(solver)),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&dyn Solver> {
+        self.iter().find(|s| s.day() == day && s.part() == part)
+    }
+
+    /// Looks a solver up by its identifier, e.g. `day01a`.
+    pub fn find(&self, id: &str) -> Option<&dyn Solver> {
+        self.iter().find(|s| s.id() == id)
+    }
+
+    /// Returns the solvers of a given day.
+    pub fn day(&self, day: u8) -> impl Iterator<Item = &dyn Solver> {
+        self.iter().filter(move |s| s.day() == day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.iter().map(|s| s.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::{forty_two, length};
+
+    #[test]
+    fn test_registry_ordering() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(2, Part::B, "Two", length, forty_two));
+        registry.register(Solution::new(1, Part::A, "One", length, forty_two));
+        registry.register(Solution::new(2, Part::A, "Two", length, forty_two));
+        let ids = registry.iter().map(|s| s.id()).collect::<Vec<String>>();
+        assert_eq!(ids, vec!["day01a", "day02a", "day02b"]);
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let mut registry = Registry::new();
+        registry.register(Solution::new(1, Part::A, "One", length, forty_two));
+        registry.register(Solution::new(1, Part::A, "One again", length, forty_two));
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.find("day01a").unwrap().name(), "One again");
+        assert_eq!(
+            registry.get(1, Part::A).unwrap().solve("x"),
+            Ok(Answer::Number(42))
+        );
+        assert!(registry.get(1, Part::B).is_none());
+        assert_eq!(registry.day(1).count(), 1);
+    }
+
+    #[test]
+    fn test_answer_from_number() {
+        assert_eq!(Answer::from(-3), Answer::Number(-3));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".into())
+        );
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    }
+}
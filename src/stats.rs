@@ -0,0 +1,211 @@
+//! Descriptive statistics over slices of numbers.
+//!
+//! Every function returns `None` for an empty slice instead of panicking.
+//! Values are compared with `partial_cmp`, so NaNs are not supported.
+//!
+//! `min`, `max`, the lower and upper medians and `mode` work in the type of
+//! the values and are exact. The other statistics are computed on `f64`, so
+//! integers beyond 2^53 in magnitude are rounded before being summed, and
+//! their results are approximate.
+
+use std::cmp::Ordering;
+
+/// A number the statistics can be computed on.
+pub trait Number: Copy + PartialOrd {
+    fn to_f64(self) -> f64;
+
+    /// Converts back from a float, saturating at the bounds of the type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// How to round a statistic back to the type of the values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+    Nearest,
+}
+
+impl Rounding {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Rounding::Down => value.floor(),
+            Rounding::Up => value.ceil(),
+            Rounding::Nearest => value.round(),
+        }
+    }
+}
+
+fn compare<T: Number>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+fn sorted<T: Number>(values: &[T]) -> Vec<T> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(compare);
+    sorted
+}
+
+pub fn min<T: Number>(values: &[T]) -> Option<T> {
+    values.iter().copied().min_by(compare)
+}
+
+pub fn max<T: Number>(values: &[T]) -> Option<T> {
+    values.iter().copied().max_by(compare)
+}
+
+pub fn min_max<T: Number>(values: &[T]) -> Option<(T, T)> {
+    Some((min(values)?, max(values)?))
+}
+
+pub fn mean<T: Number>(values: &[T]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().map(|v| v.to_f64()).sum::<f64>() / values.len() as f64)
+}
+
+/// Returns the mean, rounded to the type of the values.
+///
+/// Computed on `f64`, see the precision limit in the module documentation.
+pub fn mean_rounded<T: Number>(values: &[T], rounding: Rounding) -> Option<T> {
+    mean(values).map(|mean| T::from_f64(rounding.apply(mean)))
+}
+
+/// Returns the lower of the two middle values for an even number of values.
+pub fn median_lower<T: Number>(values: &[T]) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(sorted(values)[(values.len() - 1) / 2])
+}
+
+/// Returns the upper of the two middle values for an even number of values.
+pub fn median_upper<T: Number>(values: &[T]) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(sorted(values)[values.len() / 2])
+}
+
+/// Returns the median, averaging the two middle values for an even number of
+/// values.
+pub fn median<T: Number>(values: &[T]) -> Option<f64> {
+    Some((median_lower(values)?.to_f64() + median_upper(values)?.to_f64()) / 2.0)
+}
+
+/// Returns the most frequent value, the smallest one in case of a tie.
+pub fn mode<T: Number>(values: &[T]) -> Option<T> {
+    let sorted = sorted(values);
+    let mut best: Option<(T, usize)> = None;
+    let mut start = 0;
+    for end in 1..=sorted.len() {
+        if end == sorted.len() || compare(&sorted[start], &sorted[end]) != Ordering::Equal {
+            let count = end - start;
+            if best.is_none_or(|(_, best_count)| count > best_count) {
+                best = Some((sorted[start], count));
+            }
+            start = end;
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+/// Returns the `p`th percentile, `p` going from 0 to 100.
+///
+/// Interpolates linearly between the two closest values, so the 50th
+/// percentile is the median.
+pub fn percentile<T: Number>(values: &[T], p: f64) -> Option<f64> {
+    if values.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let sorted = sorted(values);
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    let (a, b) = (sorted[lower].to_f64(), sorted[upper].to_f64());
+    Some(a + (b - a) * (rank - lower as f64))
+}
+
+/// Returns the population variance.
+pub fn variance<T: Number>(values: &[T]) -> Option<f64> {
+    let mean = mean(values)?;
+    let squares = values.iter().map(|v| (v.to_f64() - mean).powi(2));
+    Some(squares.sum::<f64>() / values.len() as f64)
+}
+
+pub fn std_dev<T: Number>(values: &[T]) -> Option<f64> {
+    variance(values).map(f64::sqrt)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VALUES: [i32; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+    #[test]
+    fn test_empty() {
+        let empty: [i32; 0] = [];
+        assert_eq!(mean(&empty), None);
+        assert_eq!(median(&empty), None);
+        assert_eq!(mode(&empty), None);
+        assert_eq!(percentile(&empty, 50.0), None);
+        assert_eq!(variance(&empty), None);
+        assert_eq!(min_max(&empty), None);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median_lower(&VALUES), Some(2));
+        assert_eq!(median_upper(&VALUES), Some(2));
+        assert_eq!(median(&[3, 1]), Some(2.0));
+        assert_eq!(median_lower(&[3, 1]), Some(1));
+        assert_eq!(median_upper(&[3, 1]), Some(3));
+        assert_eq!(median(&[5u8]), Some(5.0));
+        assert_eq!(median(&[1.5, 0.5, 4.0]), Some(1.5));
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(mean(&VALUES), Some(4.9));
+        assert_eq!(mean_rounded(&VALUES, Rounding::Down), Some(4));
+        assert_eq!(mean_rounded(&VALUES, Rounding::Up), Some(5));
+        assert_eq!(mean_rounded(&VALUES, Rounding::Nearest), Some(5));
+        assert_eq!(mean_rounded(&[-1, -2], Rounding::Down), Some(-2));
+    }
+
+    #[test]
+    fn test_others() {
+        assert_eq!(mode(&VALUES), Some(2));
+        assert_eq!(mode(&[3, 1, 3, 1]), Some(1));
+        assert_eq!(min_max(&VALUES), Some((0, 16)));
+        assert_eq!(percentile(&VALUES, 0.0), Some(0.0));
+        assert_eq!(percentile(&VALUES, 100.0), Some(16.0));
+        assert_eq!(percentile(&VALUES, 50.0), median(&VALUES));
+        assert_eq!(percentile(&[1, 2, 3, 4, 5], 25.0), Some(2.0));
+        assert_eq!(percentile(&VALUES, 101.0), None);
+        assert_eq!(variance(&[2, 4, 4, 4, 5, 5, 7, 9]), Some(4.0));
+        assert_eq!(
+            std_dev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]),
+            Some(2.0)
+        );
+    }
+}
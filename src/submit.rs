@@ -0,0 +1,293 @@
+use crate::config::Config;
+use crate::{http, Part};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// The file remembering until when submissions are on hold, in the assets
+/// directory.
+pub const DEFAULT_COOLDOWN: &str = ".aoc2021_cooldown";
+
+/// How long the website makes us wait after a wrong answer, unless it says
+/// otherwise.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// A wrong answer, without telling whether it is too high or too low.
+    Wrong,
+    /// The previous answer was submitted too recently to try again yet.
+    Wait(Duration),
+    /// The puzzle is already solved, or its first part is not.
+    WrongLevel,
+    /// A page we could not make sense of, reduced to its text.
+    Unknown(String),
+}
+
+impl Feedback {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Feedback::TooHigh | Feedback::TooLow | Feedback::Wrong)
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "That's the right answer!"),
+            Feedback::TooHigh => write!(f, "That's not the right answer: too high."),
+            Feedback::TooLow => write!(f, "That's not the right answer: too low."),
+            Feedback::Wrong => write!(f, "That's not the right answer."),
+            Feedback::Wait(wait) => write!(
+                f,
+                "Submitted too recently, try again in {}s.",
+                wait.as_secs()
+            ),
+            Feedback::WrongLevel => {
+                write!(f, "Not the right level: already solved, or part a is not?")
+            }
+            Feedback::Unknown(text) => write!(f, "Unexpected answer: {}", text),
+        }
+    }
+}
+
+/// Parses the page the website answers a submission with.
+pub fn parse_feedback(page: &str) -> Feedback {
+    let text = page_text(page);
+    if text.contains("That's the right answer") {
+        Feedback::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Feedback::TooHigh
+        } else if text.contains("your answer is too low") {
+            Feedback::TooLow
+        } else {
+            Feedback::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Feedback::Wait(parse_wait(&text).unwrap_or(WRONG_ANSWER_WAIT))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Feedback::WrongLevel
+    } else {
+        Feedback::Unknown(text)
+    }
+}
+
+/// Returns the text of the `<main>` element of a page, without the tags.
+fn page_text(page: &str) -> String {
+    let main = match (page.find("<main>"), page.find("</main>")) {
+        (Some(start), Some(end)) if start < end => &page[start + 6..end],
+        _ => page,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in main.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Parses a wait like `You have 4m 32s left to wait.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left")?;
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (value, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses how long to wait after a wrong answer, e.g. `Please wait one minute
+/// before trying again.`
+fn wrong_answer_wait(text: &str) -> Duration {
+    let minutes = [("one", 1), ("two", 2), ("five", 5), ("ten", 10)]
+        .into_iter()
+        .find(|(number, _)| text.contains(&format!("wait {} minute", number)));
+    minutes.map_or(WRONG_ANSWER_WAIT, |(_, minutes)| {
+        Duration::from_secs(minutes * 60)
+    })
+}
+
+/// Remembers until when the website refuses new submissions, so that we wait
+/// without asking it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cooldown {
+    /// Seconds since the Unix epoch.
+    pub until: u64,
+}
+
+impl Cooldown {
+    /// Loads the cooldown, which is over when the file does not exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .trim()
+                .parse()
+                .map(|until| Cooldown { until })
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Cooldown::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, format!("{}\n", self.until))
+    }
+
+    /// Returns how long to wait from `now` before submitting again.
+    pub fn remaining(&self, now: u64) -> Option<Duration> {
+        match self.until > now {
+            true => Some(Duration::from_secs(self.until - now)),
+            false => None,
+        }
+    }
+}
+
+/// Submits the answer of a puzzle of `year`, unless the cooldown saved at
+/// `cooldown_path` is not over at `now`.
+///
+/// Saves the cooldown the website imposes after a wrong or early answer.
+pub fn submit_answer<P: AsRef<Path>>(
+    config: &Config,
+    cooldown_path: P,
+    now: u64,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> io::Result<Feedback> {
+    if answer.contains('\n') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the answer spans several lines, read it and submit it by hand",
+        ));
+    }
+    let cooldown = Cooldown::load(&cooldown_path)?;
+    if let Some(remaining) = cooldown.remaining(now) {
+        return Ok(Feedback::Wait(remaining));
+    }
+    let level = match part {
+        Part::A => 1,
+        Part::B => 2,
+    };
+    let response = http::post_form(
+        &config.url(year, &format!("day/{}/answer", day)),
+        &config.cookie()?,
+        &format!("level={}&answer={}", level, http::form_encode(answer)),
+    )?;
+    if !response.is_success() {
+        return Err(io::Error::other(format!(
+            "the server answered {}: {}",
+            response.status,
+            response.body.lines().next().unwrap_or_default()
+        )));
+    }
+    let feedback = parse_feedback(&response.body);
+    let wait = match &feedback {
+        Feedback::Wait(wait) => Some(*wait),
+        feedback if feedback.is_wrong() => Some(wrong_answer_wait(&page_text(&response.body))),
+        _ => None,
+    };
+    if let Some(wait) = wait {
+        Cooldown {
+            until: now + wait.as_secs(),
+        }
+        .save(&cooldown_path)?;
+    }
+    Ok(feedback)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::mock;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        let correct = "That's the right answer! You are <em>one gold star</em> closer.";
+        assert_eq!(parse_feedback(&page(correct)), Feedback::Correct);
+        let too_low = "That's not the right answer; your answer is too low. \
+                       Please wait one minute before trying again. (You guessed <span>530</span>.)";
+        assert_eq!(parse_feedback(&page(too_low)), Feedback::TooLow);
+        assert_eq!(
+            wrong_answer_wait(&page_text(&page(too_low))),
+            Duration::from_secs(60)
+        );
+        let too_high =
+            "That's not the right answer; your answer is too high. Please wait five minutes";
+        assert_eq!(parse_feedback(&page(too_high)), Feedback::TooHigh);
+        assert_eq!(wrong_answer_wait(too_high), Duration::from_secs(300));
+        let wait = "You gave an answer too recently; you have to wait after submitting an answer \
+                    before trying again.  You have 4m 32s left to wait.";
+        assert_eq!(
+            parse_feedback(&page(wait)),
+            Feedback::Wait(Duration::from_secs(272))
+        );
+        let level = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(parse_feedback(&page(level)), Feedback::WrongLevel);
+        assert_eq!(
+            parse_feedback("<p>Hello</p>"),
+            Feedback::Unknown("Hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let cooldown =
+            std::env::temp_dir().join(format!("aoc2021_cooldown_{}", std::process::id()));
+        let _ = fs::remove_file(&cooldown);
+        let (base_url, server) = mock::serve(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let config = Config {
+            session: Some("abc123".to_string()),
+            base_url,
+        };
+
+        let feedback = submit_answer(&config, &cooldown, 1000, 2021, 9, Part::A, "530").unwrap();
+        assert_eq!(feedback, Feedback::TooLow);
+        assert_eq!(Cooldown::load(&cooldown).unwrap().until, 1060);
+        // Refused locally, without asking the server.
+        let feedback = submit_answer(&config, &cooldown, 1030, 2021, 9, Part::A, "531").unwrap();
+        assert_eq!(feedback, Feedback::Wait(Duration::from_secs(30)));
+        let feedback = submit_answer(&config, &cooldown, 1060, 2021, 9, Part::A, "532").unwrap();
+        assert_eq!(feedback, Feedback::Correct);
+        assert!(submit_answer(&config, &cooldown, 1060, 2021, 13, Part::B, "#.\n.#").is_err());
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2021/day/9/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=530"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=532"));
+        fs::remove_file(&cooldown).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{input_from_string, Answer, AocError, Part, Registry, Solution};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(1, Part::A, "Sonar Sweep", parse, part_a));
+    registry.register(Solution::new(1, Part::B, "Sonar Sweep", parse, part_b));
+}
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 1,
+        name: "increases",
+        help: "Count the increases of the sums of sliding windows",
+        param: ("window", 3),
+        run: |input, window| Ok(count_window_increases(&parse(input)?, window.max(1)).to_string()),
+    });
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_b)
+}
+
+pub fn day01a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(1)?)?.to_string())
+}
+
+pub fn day01b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(1)?)?.to_string())
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, AocError> {
+    input_from_string::<i64>(input)
+}
+
+fn part_a(data: Vec<i64>) -> Answer {
+    count_increases(&data).into()
+}
+
+fn part_b(data: Vec<i64>) -> Answer {
+    count_window_increases(&data, 3).into()
+}
+
+fn count_increases(data: &[i64]) -> i64 {
+    data.windows(2)
+        .map(|i| if i[0] < i[1] { 1 } else { 0 })
+        .sum::<i64>()
+}
+
+fn count_window_increases(data: &[i64], window: usize) -> i64 {
+    let measurement_windows = data
+        .windows(window)
+        .map(|w| w.iter().sum::<i64>())
+        .collect::<Vec<i64>>();
+    count_increases(&measurement_windows)
+}
+
+/// Generates a sonar sweep of `size` depths, mostly going down.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..=200);
+    (0..size)
+        .map(|_| {
+            depth = (depth + rng.range(-10..=15)).max(0);
+            format!("{}\n", depth)
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, Answer, AocError, Part, Registry, Solution};
+use std::cmp::PartialEq;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Command {
+    direction: Direction,
+    unit: u8,
+}
+
+impl FromStr for Command {
+    type Err = AocError;
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        let (direction, unit) = command
+            .split_once(' ')
+            .ok_or_else(|| AocError::invalid(command, "expected a direction and a unit"))?;
+        let direction = match direction {
+            "forward" => Direction::Forward,
+            "up" => Direction::Up,
+            "down" => Direction::Down,
+            _ => return Err(AocError::invalid(command, "unknown direction")),
+        };
+        let unit = unit
+            .parse::<u8>()
+            .map_err(|e| AocError::invalid(command, format!("invalid unit: {}", e)))?;
+        Ok(Command { direction, unit })
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(2, Part::A, "Dive!", parse, part_a));
+    registry.register(Solution::new(2, Part::B, "Dive!", parse, part_b));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_b)
+}
+
+pub fn day02a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(2)?)?.to_string())
+}
+
+pub fn day02b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(2)?)?.to_string())
+}
+
+fn parse(input: &str) -> Result<Vec<Command>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+fn part_a(commands: Vec<Command>) -> Answer {
+    planned_course(&commands).into()
+}
+
+fn part_b(commands: Vec<Command>) -> Answer {
+    planned_course_with_aim(&commands).into()
+}
+
+fn planned_course(commands: &[Command]) -> i32 {
+    let horizontal = commands
+        .iter()
+        .filter(|c| c.direction == Direction::Forward)
+        .map(|c| c.unit as u32)
+        .sum::<u32>();
+    let depth = commands
+        .iter()
+        .map(|c| match c.direction {
+            Direction::Up => -(c.unit as i32),
+            Direction::Down => c.unit as i32,
+            Direction::Forward => 0,
+        })
+        .sum::<i32>();
+    (horizontal as i32) * depth
+}
+
+fn planned_course_with_aim(commands: &[Command]) -> i64 {
+    let horizontal = commands
+        .iter()
+        .filter(|c| c.direction == Direction::Forward)
+        .map(|c| c.unit as u32)
+        .sum::<u32>();
+    let mut depth: i64 = 0;
+    let mut aim: i64 = 0;
+    for command in commands {
+        match command.direction {
+            Direction::Up => aim -= command.unit as i64,
+            Direction::Down => aim += command.unit as i64,
+            Direction::Forward => depth += aim * (command.unit as i64),
+        }
+    }
+    (horizontal as i64) * depth
+}
+
+/// Generates `size` commands, never going up above the surface.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    (0..size)
+        .map(|_| {
+            let units = rng.range(1..=9);
+            let direction = match rng.below(3) {
+                0 => "forward",
+                1 => "down",
+                _ if depth < units => "down",
+                _ => "up",
+            };
+            match direction {
+                "down" => depth += units,
+                "up" => depth -= units,
+                _ => (),
+            }
+            format!("{} {}\n", direction, units)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("forward 5\nsideways 3").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unknown direction: `sideways 3`");
+        assert!(parse("up").is_err());
+        assert!(parse("up -1").is_err());
+    }
+}
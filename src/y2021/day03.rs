@@ -0,0 +1,157 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, Answer, AocError, Grid, Part, Registry, Solution};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        3,
+        Part::A,
+        "Binary Diagnostic",
+        parse,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        3,
+        Part::B,
+        "Binary Diagnostic",
+        parse,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_b)
+}
+
+pub fn day03a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(3)?)?.to_string())
+}
+
+pub fn day03b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(3)?)?.to_string())
+}
+
+/// Parses the diagnostic report, made of binary numbers of the same length.
+///
+/// Each row of the grid holds the bits of a number.
+fn parse(input: &str) -> Result<Grid<u32>, AocError> {
+    let mut width = None;
+    let data = parse_lines(input, |line| {
+        if *width.get_or_insert(line.len()) != line.len() {
+            return Err(AocError::invalid(line, "inconsistent number length"));
+        }
+        line.chars()
+            .map(|c| c.to_digit(2))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(|| AocError::invalid(line, "expected a binary number"))
+    })?;
+    match data.is_empty() {
+        true => Err(AocError::new("empty diagnostic report")),
+        false => Grid::from_rows(data),
+    }
+}
+
+fn part_a(report: Grid<u32>) -> Answer {
+    power_consumption(&report).into()
+}
+
+fn part_b(report: Grid<u32>) -> Answer {
+    life_support_rating(&report).into()
+}
+
+fn to_number(bits: &[u32]) -> u32 {
+    bits.iter().fold(0, |number, bit| number << 1 | bit)
+}
+
+fn power_consumption(report: &Grid<u32>) -> u32 {
+    let half = (report.height() / 2) as u32;
+    let gamma = report
+        .columns()
+        .map(|column| u32::from(column.sum::<u32>() > half))
+        .collect::<Vec<u32>>();
+    let epsilon = gamma.iter().map(|bit| 1 - bit).collect::<Vec<u32>>();
+    to_number(&gamma) * to_number(&epsilon)
+}
+
+fn filtering(report: &Grid<u32>, most_common_criteria: bool) -> u32 {
+    let mut numbers = report.rows().collect::<Vec<&[u32]>>();
+
+    for col in 0..report.width() {
+        // Determine the most common value for the current column.
+        let row_sum = numbers.iter().map(|n| n[col]).sum::<u32>();
+        let half = (numbers.len() as f32 / 2.0).ceil() as u32;
+        let most_common = u32::from((row_sum >= half) == most_common_criteria);
+
+        // Remove the numbers which DON'T have the most common value, unless
+        // none of them has it.
+        if numbers.iter().any(|n| n[col] == most_common) {
+            numbers.retain(|n| n[col] == most_common);
+        }
+
+        if numbers.len() == 1 {
+            break;
+        }
+    }
+    to_number(numbers[0])
+}
+
+fn life_support_rating(report: &Grid<u32>) -> u32 {
+    let oxygen_generator_rating = filtering(report, true);
+    let co2_scrubber_rating = filtering(report, false);
+    oxygen_generator_rating * co2_scrubber_rating
+}
+
+/// Generates a diagnostic report of `size` twelve-bit numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{:012b}\n", rng.below(1 << 12)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW_INPUT: &str = "00100
+    11110
+    10110
+    10111
+    10101
+    01111
+    00111
+    11100
+    10000
+    11001
+    00010
+    01010";
+
+    #[test]
+    fn day_03_part_a_test() {
+        let input = parse(RAW_INPUT).unwrap();
+        assert_eq!(198, power_consumption(&input));
+    }
+
+    #[test]
+    fn day_03_parse_errors() {
+        let error = parse("00100\n11110\n1011").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: inconsistent number length: `1011`"
+        );
+        assert!(parse("00100\n11210").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn day_03_part_b_test() {
+        let input = parse(RAW_INPUT).unwrap();
+        assert_eq!(230, life_support_rating(&input));
+        // Every number has the same first bits.
+        let input = parse("1010\n1011").unwrap();
+        assert_eq!(11 * 10, life_support_rating(&input));
+    }
+}
@@ -1,5 +1,8 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{debug, Answer, AocError, Part, Registry, Solution};
 use std::fmt;
-use std::fs;
 
 const GRID_ROWS: usize = 5;
 const GRID_COLS: usize = 5;
@@ -21,7 +24,8 @@ impl Number {
 
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:>3}", self.value)
+        let mark = if self.called { '*' } else { ' ' };
+        write!(f, "{:>3}{}", self.value, mark)
     }
 }
 
@@ -33,15 +37,19 @@ struct BingoCard {
 }
 
 impl BingoCard {
-    pub fn from_slice(numbers: &[u8], rows: usize, columns: usize) -> Self {
+    pub fn from_slice(numbers: &[u8], rows: usize, columns: usize) -> Result<Self, AocError> {
         if rows * columns != numbers.len() {
-            panic!("Invalid bingo card shape.")
+            return Err(AocError::new(format!(
+                "invalid bingo card shape: expected {} numbers, found {}",
+                rows * columns,
+                numbers.len()
+            )));
         }
-        BingoCard {
+        Ok(BingoCard {
             grid: numbers.iter().map(|&n| Number::new(n)).collect(),
             rows,
             columns,
-        }
+        })
     }
 
     pub fn has_full_row(&self) -> bool {
@@ -105,37 +113,51 @@ impl fmt::Display for BingoCard {
     }
 }
 
-fn read_input(input: &str) -> (Vec<u8>, Vec<BingoCard>) {
+fn read_input(input: &str) -> Result<(Vec<u8>, Vec<BingoCard>), AocError> {
     // Prepare the line iterator.
-    let mut iter_values = input.lines();
+    let mut iter_values = input.lines().enumerate();
 
     // Extract the first line as the list of numbers that were called.
-    let calls: Vec<u8> = iter_values
+    let (_, first_line) = iter_values
         .next()
-        .unwrap()
+        .ok_or_else(|| AocError::new("empty input"))?;
+    let calls: Vec<u8> = first_line
         .split(',')
-        .map(|n| n.parse().unwrap())
-        .collect();
+        .map(|n| parse_number(n, first_line))
+        .collect::<Result<Vec<u8>, AocError>>()
+        .map_err(|e| e.with_line(1))?;
     iter_values.next();
 
-    // Extract the bingo cards.
+    // Extract the bingo cards, a blank line closing each of them.
     let mut bingo_cards: Vec<BingoCard> = Vec::new();
-    let mut card: Vec<Vec<u8>> = vec![];
-    while let Some(line) = iter_values.next() {
+    let mut card: Vec<u8> = vec![];
+    let mut card_line = 0;
+    for (i, line) in iter_values.chain([(0, "")]) {
+        let line = line.trim();
         if line.is_empty() {
-            let flat_card: Vec<u8> = card.clone().into_iter().flatten().collect();
-            let bingo_card = BingoCard::from_slice(&flat_card, GRID_ROWS, GRID_COLS);
-            bingo_cards.push(bingo_card);
-            card = vec![];
+            if !card.is_empty() {
+                let bingo_card = BingoCard::from_slice(&card, GRID_ROWS, GRID_COLS)
+                    .map_err(|e| e.with_line(card_line))?;
+                bingo_cards.push(bingo_card);
+                card = vec![];
+            }
         } else {
-            let card_line: Vec<u8> = line
-                .split_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect();
-            card.push(card_line);
+            if card.is_empty() {
+                card_line = i + 1;
+            }
+            for n in line.split_whitespace() {
+                card.push(parse_number(n, line).map_err(|e| e.with_line(i + 1))?);
+            }
         }
     }
-    (calls, bingo_cards)
+    Ok((calls, bingo_cards))
+}
+
+fn parse_number(number: &str, line: &str) -> Result<u8, AocError> {
+    number
+        .trim()
+        .parse()
+        .map_err(|e| AocError::invalid(line, format!("invalid number `{}`: {}", number, e)))
 }
 
 fn process_cards(calls: Vec<u8>, mut bingo_cards: Vec<BingoCard>) -> u32 {
@@ -159,9 +181,12 @@ fn last_winning(calls: Vec<u8>, mut bingo_cards: Vec<BingoCard>) -> u32 {
                 all_won[i] = true;
             }
             if all_won.iter().all(|&b| b) {
-                dbg!(&call);
-                dbg!(&bingo_card.unmarked());
-                dbg!(&bingo_card.grid[0]);
+                debug!(
+                    "last card wins on call {}, {} unmarked:\n{}",
+                    call,
+                    bingo_card.unmarked(),
+                    bingo_card
+                );
                 return (call as u32) * bingo_card.unmarked();
             }
         }
@@ -169,28 +194,90 @@ fn last_winning(calls: Vec<u8>, mut bingo_cards: Vec<BingoCard>) -> u32 {
     0
 }
 
-pub fn day04a() -> String {
-    // Read the input as a string.
-    let values = fs::read_to_string("assets/day04.txt").expect("Could not load file");
-    let (calls, bingo_cards) = read_input(&values);
-    let res = process_cards(calls, bingo_cards);
-    res.to_string()
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(4, Part::A, "Giant Squid", read_input, part_a));
+    registry.register(Solution::new(4, Part::B, "Giant Squid", read_input, part_b));
+}
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 4,
+        name: "cards",
+        help: "Print the cards after a number of calls, `*` marking the called numbers",
+        param: ("calls", 0),
+        run: |input, count| {
+            let (calls, mut bingo_cards) = read_input(input)?;
+            for &call in calls.iter().take(count) {
+                bingo_cards.iter_mut().for_each(|card| card.calls(call));
+            }
+            Ok(bingo_cards
+                .iter()
+                .map(|card| match card.wins() {
+                    true => format!("{}\nBingo!", card),
+                    false => card.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join("\n\n"))
+        },
+    });
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_b)
 }
 
-pub fn day04b() -> String {
-    // Read the input as a string.
-    let values = fs::read_to_string("assets/day04.txt").expect("Could not load file");
-    let (calls, bingo_cards) = read_input(&values);
-    let res = last_winning(calls, bingo_cards);
-    res.to_string()
+pub fn day04a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(4)?)?.to_string())
+}
+
+pub fn day04b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(4)?)?.to_string())
+}
+
+fn part_a((calls, bingo_cards): (Vec<u8>, Vec<BingoCard>)) -> Answer {
+    process_cards(calls, bingo_cards).into()
+}
+
+fn part_b((calls, bingo_cards): (Vec<u8>, Vec<BingoCard>)) -> Answer {
+    last_winning(calls, bingo_cards).into()
+}
+
+/// Generates the numbers 0 to 99 in a random order, then `size` cards.
+///
+/// Every number is eventually called, so every card wins.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..100).collect::<Vec<u8>>();
+    rng.shuffle(&mut numbers);
+    let mut input = numbers
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<String>>()
+        .join(",");
+    input.push('\n');
+    for _ in 0..size {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..GRID_ROWS * GRID_COLS].chunks(GRID_COLS) {
+            let row = row
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    input
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const RAW_INPUT: &'static str =
-        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+    const RAW_INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
 22 13 17 11  0
 8  2 23  4 24
@@ -214,16 +301,27 @@ mod tests {
 
     #[test]
     pub fn test_day04_parta() {
-        let (calls, bingo_cards) = read_input(RAW_INPUT);
+        let (calls, bingo_cards) = read_input(RAW_INPUT).unwrap();
         assert_eq!(4512, process_cards(calls, bingo_cards))
     }
 
     #[test]
     pub fn test_day04_partb() {
-        let (calls, bingo_cards) = read_input(RAW_INPUT);
+        let (calls, bingo_cards) = read_input(RAW_INPUT).unwrap();
         assert_eq!(1924, last_winning(calls, bingo_cards))
     }
 
+    #[test]
+    pub fn test_day04_parse_errors() {
+        let error = read_input("7,4,x\n\n1 2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: invalid number `x`: invalid digit found in string: `7,4,x`"
+        );
+        let error = read_input("7,4\n\n1 2 3 4 5\n6 7 8 9 10\n\n1 2").unwrap_err();
+        assert_eq!(error.line(), Some(3));
+    }
+
     #[test]
     pub fn test_bingo_card_full_row() {
         let full_row: Vec<Number> = vec![
@@ -257,9 +355,9 @@ mod tests {
             rows: 2,
             columns: 3,
         };
-        assert_eq!(full_card.has_full_row(), true);
-        assert_eq!(full_card.has_full_column(), false);
-        assert_eq!(full_card.wins(), true);
+        assert!(full_card.has_full_row());
+        assert!(!full_card.has_full_column());
+        assert!(full_card.wins());
     }
 
     #[test]
@@ -295,9 +393,9 @@ mod tests {
             rows: 2,
             columns: 3,
         };
-        assert_eq!(partial_card.has_full_row(), false);
-        assert_eq!(partial_card.has_full_column(), false);
-        assert_eq!(partial_card.wins(), false);
+        assert!(!partial_card.has_full_row());
+        assert!(!partial_card.has_full_column());
+        assert!(!partial_card.wins());
     }
 
     #[test]
@@ -333,10 +431,10 @@ mod tests {
             rows: 3,
             columns: 2,
         };
-        println!("{}", full_card);
-        assert_eq!(full_card.has_full_row(), false);
-        assert_eq!(full_card.has_full_column(), true);
-        assert_eq!(full_card.wins(), true);
+        debug!("\n{}", full_card);
+        assert!(!full_card.has_full_row());
+        assert!(full_card.has_full_column());
+        assert!(full_card.wins());
     }
 
     #[test]
@@ -360,9 +458,9 @@ mod tests {
             rows: 3,
             columns: 1,
         };
-        assert_eq!(full_card.has_full_row(), true);
-        assert_eq!(full_card.has_full_column(), false);
-        assert_eq!(full_card.wins(), true);
+        assert!(full_card.has_full_row());
+        assert!(!full_card.has_full_column());
+        assert!(full_card.wins());
     }
 
     #[test]
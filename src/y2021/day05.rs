@@ -0,0 +1,215 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, Answer, AocError, Part, Point, Registry, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Line {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let line = match input.split_once(" -> ") {
+            Some((start, end)) => Line {
+                start: start.parse()?,
+                end: end.parse()?,
+            },
+            None => {
+                return Err(AocError::invalid(
+                    input,
+                    "expected a line formatted as `x1,y1 -> x2,y2`",
+                ))
+            }
+        };
+        let delta = line.end - line.start;
+        if delta.x() != 0 && delta.y() != 0 && delta.x().abs() != delta.y().abs() {
+            return Err(AocError::invalid(
+                input,
+                "expected a horizontal, vertical or 45 degrees line",
+            ));
+        }
+        Ok(line)
+    }
+}
+
+impl Line {
+    /// Expands all the points of a line.
+    ///
+    /// Expands as:
+    ///
+    /// * a vertical entry like 1,1 -> 1,3 covers points 1,1, 1,2, and 1,3.
+    /// * a horizontal entry like 9,7 -> 7,7 covers points 9,7, 8,7, and 7,7.
+    /// * diagonal linea at exactly 45 degrees like
+    ///     * an entry like 1,1 -> 3,3 covers points 1,1, 2,2, and 3,3.
+    ///     * an entry like 9,7 -> 7,9 covers points 9,7, 8,8, and 7,9.
+    ///
+    /// Parsing guarantees that the line is at one of these angles.
+    pub fn expand(self, with_diagonals: bool) -> Vec<Point> {
+        let delta = self.end - self.start;
+        let is_straight = delta.x() == 0 || delta.y() == 0;
+        if !is_straight && !with_diagonals {
+            return vec![];
+        }
+        let step = delta.signum();
+        (0..=self.start.chebyshev(&self.end))
+            .map(|i| self.start + step * i)
+            .collect()
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        5,
+        Part::A,
+        "Hydrothermal Venture",
+        read_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        5,
+        Part::B,
+        "Hydrothermal Venture",
+        read_input,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_b)
+}
+
+pub fn day05a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(5)?)?.to_string())
+}
+
+pub fn day05b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(5)?)?.to_string())
+}
+
+fn part_a(lines: Vec<Line>) -> Answer {
+    process_lines(&lines, false).into()
+}
+
+fn part_b(lines: Vec<Line>) -> Answer {
+    process_lines(&lines, true).into()
+}
+
+fn read_input(input: &str) -> Result<Vec<Line>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+fn process_lines(lines: &[Line], with_diagonals: bool) -> i32 {
+    let mut all_lines: HashMap<Point, i32> = HashMap::new();
+    for line in lines {
+        for point in line.expand(with_diagonals) {
+            *all_lines.entry(point).or_insert(0) += 1;
+        }
+    }
+    all_lines.iter().filter(|p| p.1 >= &2).count() as i32
+}
+
+/// Generates `size` horizontal, vertical or diagonal lines within a 1000 by
+/// 1000 area.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (dx, dy) = match rng.below(3) {
+                0 => (1, 0),
+                1 => (0, 1),
+                _ => (1, *rng.choose(&[-1, 1])),
+            };
+            let length = rng.range(1..=200);
+            let x1 = rng.range(0..=999 - length);
+            let y1 = match dy {
+                -1 => rng.range(length..=999),
+                _ => rng.range(0..=999 - length),
+            };
+            let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+            match rng.one_in(2) {
+                true => format!("{},{} -> {},{}\n", x1, y1, x2, y2),
+                false => format!("{},{} -> {},{}\n", x2, y2, x1, y1),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "0,9 -> 5,9
+    8,0 -> 0,8
+    9,4 -> 3,4
+    2,2 -> 2,1
+    7,0 -> 7,4
+    6,4 -> 2,0
+    0,9 -> 2,9
+    3,4 -> 1,4
+    0,0 -> 8,8
+    5,5 -> 8,2";
+
+    #[test]
+    fn test_day05_parta_sample() {
+        let lines = read_input(RAW_INPUT).unwrap();
+        assert_eq!(process_lines(&lines, false), 5)
+    }
+
+    #[test]
+    fn test_day05_partb_sample() {
+        let lines = read_input(RAW_INPUT).unwrap();
+        assert_eq!(process_lines(&lines, true), 12)
+    }
+
+    #[test]
+    fn test_day05_parse_errors() {
+        let error = read_input("0,9 -> 5,9\n8,0 -> 0,x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: invalid coordinate `x`: invalid digit found in string: `0,x`"
+        );
+        assert!(read_input("0,9 5,9").is_err());
+        let error = read_input("0,0 -> 1,5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected a horizontal, vertical or 45 degrees line: `0,0 -> 1,5`"
+        );
+    }
+
+    // #[test]
+    // fn test_line_expand() {
+    //     let line00 = Line::from_string("1,1 -> 1,3");
+    //     let line01 = Line::from_string("9,7 -> 7,7");
+    //     assert_eq!(
+    //         line00.expand(),
+    //         vec![
+    //             Point { x: 1, y: 1 },
+    //             Point { x: 1, y: 2 },
+    //             Point { x: 1, y: 3 },
+    //         ]
+    //     );
+    //     assert_eq!(
+    //         line01.expand(),
+    //         vec![
+    //             Point { x: 9, y: 7 },
+    //             Point { x: 8, y: 7 },
+    //             Point { x: 7, y: 7 },
+    //         ]
+    //     );
+    // }
+}
@@ -0,0 +1,274 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{parse_fields, trace, Answer, AocError, Part, Registry, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Lanternfish {
+    internal_timer: u8,
+}
+
+impl Lanternfish {
+    pub fn new() -> Self {
+        Lanternfish { internal_timer: 8 }
+    }
+
+    pub fn with_internal_timer(internal_timer: u8) -> Result<Self, AocError> {
+        match internal_timer {
+            0..=6 => Ok(Lanternfish { internal_timer }),
+            _ => Err(AocError::invalid(
+                &internal_timer.to_string(),
+                "a lanternfish internal timer must be between 0 and 6",
+            )),
+        }
+    }
+
+    pub fn next(&mut self) -> Option<Lanternfish> {
+        match self.internal_timer {
+            0 => {
+                self.internal_timer = 6;
+                Some(Lanternfish::new())
+            }
+            1..=8 => {
+                self.internal_timer -= 1;
+                None
+            }
+            _ => panic!("invalid internal timer value: {}", self.internal_timer),
+        }
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(6, Part::A, "Lanternfish", read_input, part_a));
+    registry.register(Solution::new(
+        6,
+        Part::B,
+        "Lanternfish",
+        read_countable_input,
+        part_b,
+    ));
+}
+
+/// The number of days of part B.
+const DAYS_B: usize = 256;
+
+/// The most days the `count` probe simulates, which bounds its recursion.
+/// Even a single lanternfish is too many to count long before.
+const MAX_COUNT_DAYS: usize = 1000;
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 6,
+        name: "count",
+        help: "Count the lanternfish after a number of days",
+        param: ("days", 80),
+        run: |input, days| {
+            let fishes = read_input(input)?;
+            match days <= MAX_COUNT_DAYS {
+                true => count_lanternfish(&fishes, days),
+                false => None,
+            }
+            .map(|count| count.to_string())
+            .ok_or_else(|| {
+                AocError::new(format!("too many lanternfish to count after {} days", days))
+            })
+        },
+    });
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    read_countable_input(input).map(part_b)
+}
+
+pub fn day06a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(6)?)?.to_string())
+}
+
+pub fn day06b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(6)?)?.to_string())
+}
+
+fn part_a(fishes: Vec<Lanternfish>) -> Answer {
+    simulate_lanternfish(&fishes, 80).into()
+}
+
+fn part_b(fishes: Vec<Lanternfish>) -> Answer {
+    count_lanternfish(&fishes, DAYS_B)
+        .expect("checked when parsing")
+        .into()
+}
+
+/// Counts the lanternfish after a number of days, unless there are more than
+/// a `usize` can hold.
+fn count_lanternfish(input: &[Lanternfish], days: usize) -> Option<usize> {
+    let mut fishes = input.to_vec();
+
+    // (fish, days left) -> produced fish
+    let mut cache: HashMap<(Lanternfish, usize), usize> = HashMap::new();
+    let mut count: usize = 0;
+    for fish in fishes.iter_mut() {
+        count = count.checked_add(memoized_simulate(&mut cache, fish, days)?)?;
+    }
+    Some(count)
+}
+
+/// Reads the lanternfish, requiring that they can be counted after the days
+/// of part B.
+fn read_countable_input(input: &str) -> Result<Vec<Lanternfish>, AocError> {
+    let fishes = read_input(input)?;
+    // No lanternfish produces more than one about to give birth.
+    let most = count_lanternfish(&[Lanternfish::with_internal_timer(0)?], DAYS_B);
+    match most.and_then(|most| most.checked_mul(fishes.len())) {
+        Some(_) => Ok(fishes),
+        None => Err(AocError::new(format!(
+            "too many lanternfish to count after {} days",
+            DAYS_B
+        ))),
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Lanternfish>, AocError> {
+    let fishes = parse_fields(input, ",", |timer| {
+        timer
+            .parse::<u8>()
+            .map_err(|e| AocError::invalid(timer, format!("invalid internal timer: {}", e)))
+            .and_then(Lanternfish::with_internal_timer)
+    })?;
+    match fishes.is_empty() {
+        true => Err(AocError::new("no lanternfish")),
+        false => Ok(fishes),
+    }
+}
+
+fn simulate_lanternfish(input: &[Lanternfish], days: i32) -> usize {
+    let mut fishes = input.to_vec();
+    for _day in 0..days {
+        let mut newborns: Vec<Lanternfish> = Vec::new();
+        for fish in fishes.iter_mut() {
+            if let Some(f) = fish.next() {
+                newborns.push(f);
+            }
+        }
+        fishes.append(&mut newborns);
+    }
+    fishes.len()
+}
+
+fn memoized_simulate(
+    cache: &mut HashMap<(Lanternfish, usize), usize>,
+    fish: &mut Lanternfish,
+    days: usize,
+) -> Option<usize> {
+    if let Some(count) = cache.get(&(*fish, days)) {
+        trace!("cache hit: {:?} -> {}", fish, count);
+        return Some(*count);
+    }
+
+    let mut count: usize = 1;
+    let cloned_fish = *fish;
+    for day in 1..=days {
+        if let Some(mut f) = fish.next() {
+            count = count.checked_add(memoized_simulate(cache, &mut f, days - day)?)?;
+        }
+    }
+    cache.insert((cloned_fish, days), count);
+    Some(count)
+}
+
+/// Generates the internal timers of `size` lanternfish.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let timers = (0..size)
+        .map(|_| rng.range(1..=5).to_string())
+        .collect::<Vec<String>>();
+    format!("{}\n", timers.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug;
+    use crate::property::{agree, shrink_items, shrink_number, shrink_vec, Checker};
+
+    const RAW_INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_day06_parta_sample() {
+        let fishes = read_input(RAW_INPUT).unwrap();
+        debug!("{:?}", fishes);
+        assert_eq!(simulate_lanternfish(&fishes, 80), 5934)
+    }
+
+    #[test]
+    fn test_day06_partb_sample() {
+        let mut fishes = read_input(RAW_INPUT).unwrap();
+        let mut cache: HashMap<(Lanternfish, usize), usize> = HashMap::new();
+        let mut count = 0;
+        // for fish in fishes.iter_mut() {
+        //     count += memoized_simulate(&mut cache, fish, 80)
+        // }
+        // assert_eq!(count, 5934);
+        for fish in fishes.iter_mut() {
+            debug!("{:?}", fish);
+            count += memoized_simulate(&mut cache, fish, 256).unwrap()
+        }
+        assert_eq!(count, 26984457539)
+    }
+
+    #[test]
+    fn test_day06_parta() {
+        assert_eq!(solve_a(&read_asset(6).unwrap()), Ok(Answer::Number(386755)))
+    }
+
+    #[test]
+    fn test_day06_simulate_agrees_with_count() {
+        Checker::new().assert(
+            |rng| {
+                let timers = (0..=rng.below(8)).map(|_| rng.below(7) as u8).collect();
+                (timers, rng.below(60))
+            },
+            |(timers, days): &(Vec<u8>, usize)| {
+                let mut candidates = shrink_number(*days)
+                    .into_iter()
+                    .map(|days| (timers.clone(), days))
+                    .collect::<Vec<_>>();
+                let shorter = shrink_vec(timers).into_iter();
+                let smaller = shrink_items(timers, |&t| {
+                    shrink_number(t as usize)
+                        .into_iter()
+                        .map(|t| t as u8)
+                        .collect()
+                });
+                candidates.extend(shorter.chain(smaller).map(|timers| (timers, *days)));
+                candidates
+            },
+            |(timers, days)| {
+                let fishes = timers
+                    .iter()
+                    .map(|&t| Lanternfish::with_internal_timer(t).unwrap())
+                    .collect::<Vec<Lanternfish>>();
+                agree(
+                    Some(simulate_lanternfish(&fishes, *days as i32)),
+                    count_lanternfish(&fishes, *days),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_day06_parse_errors() {
+        let error = read_input("3,4,9,1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "field 3: a lanternfish internal timer must be between 0 and 6: `9`"
+        );
+        assert!(read_input("3,4,x").is_err());
+        assert!(read_input("").is_err());
+        assert_eq!(read_input("3,4,3,1,2\n").unwrap().len(), 5);
+        assert_eq!(read_countable_input(RAW_INPUT), read_input(RAW_INPUT));
+    }
+}
@@ -0,0 +1,165 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::stats::{self, Rounding};
+use crate::{input_sep_from_string, Answer, AocError, Part, Registry, Solution};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        7,
+        Part::A,
+        "The Treachery of Whales",
+        parse,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        7,
+        Part::B,
+        "The Treachery of Whales",
+        parse,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse(input).map(part_b)
+}
+
+pub fn day07a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(7)?)?.to_string())
+}
+
+pub fn day07b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(7)?)?.to_string())
+}
+
+fn parse(input: &str) -> Result<Vec<i32>, AocError> {
+    input_sep_from_string::<i32>(input, ",")
+}
+
+fn part_a(positions: Vec<i32>) -> Answer {
+    process_a(&positions).into()
+}
+
+fn part_b(positions: Vec<i32>) -> Answer {
+    process_b(&positions).into()
+}
+
+/// Returns the fuel needed by the crabs to move to `alignment`, given the
+/// cost of moving over a distance.
+fn fuel(positions: &[i32], alignment: i32, cost: fn(i32) -> i32) -> i32 {
+    positions
+        .iter()
+        .map(|&position| cost((position - alignment).abs()))
+        .sum()
+}
+
+/// Moving over a distance `d` costs `d`.
+fn linear_cost(d: i32) -> i32 {
+    d
+}
+
+/// Each step costing one more than the previous one, moving over a distance
+/// `d` costs `d * (d + 1) / 2`.
+fn increasing_cost(d: i32) -> i32 {
+    d * (d + 1) / 2
+}
+
+/// Aligns the crabs on every position between the outermost ones, keeping the
+/// cheapest alignment. Slow, but obviously right.
+pub fn brute_force(positions: &[i32], cost: fn(i32) -> i32) -> i32 {
+    stats::min_max(positions).map_or(0, |(min, max)| {
+        (min..=max)
+            .map(|alignment| fuel(positions, alignment, cost))
+            .min()
+            .unwrap_or(0)
+    })
+}
+
+/// Aligns the crabs on the median, which minimizes the sum of the distances.
+fn process_a(input: &[i32]) -> i32 {
+    stats::median_lower(input).map_or(0, |median| fuel(input, median, linear_cost))
+}
+
+/// Aligns the crabs around the mean.
+///
+/// The sum of the increasing costs is minimized within half a step of the
+/// mean, so the answer is either the mean rounded down or up.
+fn process_b(input: &[i32]) -> i32 {
+    [Rounding::Down, Rounding::Up]
+        .into_iter()
+        .filter_map(|rounding| stats::mean_rounded(input, rounding))
+        .map(|alignment| fuel(input, alignment, increasing_cost))
+        .min()
+        .unwrap_or(0)
+}
+
+/// Generates the positions of `size` crabs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let positions = (0..size)
+        .map(|_| rng.range(0..=2 * size as i64).to_string())
+        .collect::<Vec<String>>();
+    format!("{}\n", positions.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::property::{agree, shrink_items, shrink_number, shrink_vec, Checker};
+
+    const RAW_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_day07_parta_sample() {
+        let input = RAW_INPUT
+            .trim()
+            .split(',')
+            .filter_map(|s| s.parse::<i32>().ok())
+            .collect::<Vec<i32>>();
+        assert_eq!(process_a(&input), 37);
+        assert_eq!(brute_force(&input, linear_cost), 37);
+    }
+
+    #[test]
+    fn test_day07_partb_sample() {
+        let input = RAW_INPUT
+            .trim()
+            .split(',')
+            .filter_map(|s| s.parse::<i32>().ok())
+            .collect::<Vec<i32>>();
+        assert_eq!(process_b(&input), 168);
+        assert_eq!(brute_force(&input, increasing_cost), 168);
+    }
+
+    #[test]
+    fn test_day07_closed_forms_agree_with_brute_force() {
+        let generate = |rng: &mut Rng| {
+            let spread = rng.range(1..=100);
+            (0..rng.below(20))
+                .map(|_| rng.range(0..=spread) as i32)
+                .collect::<Vec<i32>>()
+        };
+        let shrink = |positions: &Vec<i32>| {
+            let mut candidates = shrink_vec(positions);
+            candidates.extend(shrink_items(positions, |&p| {
+                shrink_number(p as usize)
+                    .into_iter()
+                    .map(|p| p as i32)
+                    .collect()
+            }));
+            candidates
+        };
+        Checker::new().assert(generate, shrink, |positions| {
+            agree(brute_force(positions, linear_cost), process_a(positions))
+        });
+        Checker::new().assert(generate, shrink, |positions| {
+            agree(
+                brute_force(positions, increasing_cost),
+                process_b(positions),
+            )
+        });
+    }
+}
@@ -0,0 +1,286 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, warn, Answer, AocError, Part, Registry, Solution};
+use std::str::FromStr;
+
+/// A note entry: the ten unique signal patterns and the four digit output value.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    patterns: String,
+    output: String,
+}
+
+impl FromStr for Entry {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (patterns, output) = line.split_once('|').ok_or_else(|| {
+            AocError::invalid(line, "expected patterns and output separated by `|`")
+        })?;
+        let valid = |digits: &str, count: usize| {
+            digits.split_whitespace().count() == count
+                && digits
+                    .split_whitespace()
+                    .all(|d| d.chars().all(|c| ('a'..='g').contains(&c)))
+        };
+        if !valid(patterns, 10) {
+            return Err(AocError::invalid(line, "expected ten signal patterns"));
+        }
+        if !valid(output, 4) {
+            return Err(AocError::invalid(
+                line,
+                "expected a four digit output value",
+            ));
+        }
+        let entry = Entry {
+            patterns: patterns.trim().to_string(),
+            output: output.trim().to_string(),
+        };
+        match decode(&entry) {
+            Some(_) => Ok(entry),
+            None => Err(AocError::invalid(
+                line,
+                "expected the patterns of the ten digits, and an output made of them",
+            )),
+        }
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        8,
+        Part::A,
+        "Seven Segment Search",
+        parse_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        8,
+        Part::B,
+        "Seven Segment Search",
+        parse_input,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_b)
+}
+
+pub fn day08a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(8)?)?.to_string())
+}
+
+pub fn day08b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(8)?)?.to_string())
+}
+
+fn part_a(entries: Vec<Entry>) -> Answer {
+    process_a(&entries).into()
+}
+
+fn part_b(entries: Vec<Entry>) -> Answer {
+    process_b(&entries).into()
+}
+
+fn process_a(input: &[Entry]) -> usize {
+    input.iter().map(|e| count_easy_digits(&e.output)).sum()
+}
+
+// Parsing rejects the entries which cannot be decoded.
+fn process_b(input: &[Entry]) -> i32 {
+    input.iter().filter_map(decode).sum()
+}
+
+/// Reads the note entries, skipping with a warning the lines without a `|`,
+/// which cannot be entries. Our input ends with such a stray line.
+fn parse_input(input: &str) -> Result<Vec<Entry>, AocError> {
+    let entries = parse_lines(input, |line| match line.contains('|') {
+        true => line.parse().map(Some),
+        false => {
+            warn!("skipped a line which is not a note entry: `{}`", line);
+            Ok(None)
+        }
+    })?;
+    let entries = entries.into_iter().flatten().collect::<Vec<Entry>>();
+    match entries.is_empty() {
+        true => Err(AocError::new("no note entry")),
+        false => Ok(entries),
+    }
+}
+
+fn count_easy_digits(output: &str) -> usize {
+    output
+        .split_whitespace()
+        .filter(|&digit| is_easy_digit(digit))
+        .count()
+}
+
+// Easy digits are defined as follow:
+// * 1 is the only digit that uses two segments
+// * 4 is the only digit that uses four segments
+// * 7 is the only digit that uses three segments
+// * 8 is the only digit that uses seven segments
+fn is_easy_digit(digit: &str) -> bool {
+    matches!(digit.len(), 2 | 4 | 3 | 7)
+}
+
+// Represents the segments of a digit as a bit mask, `a` being the lowest bit.
+fn segments(digit: &str) -> u8 {
+    digit
+        .bytes()
+        .filter(|b| (b'a'..=b'g').contains(b))
+        .fold(0, |mask, b| mask | 1 << (b - b'a'))
+}
+
+// Deduces the wiring from the easy digits:
+// * 9 is the only six-segment digit containing all the segments of 4
+// * 0 is the other six-segment digit containing the segments of 1
+// * 3 is the only five-segment digit containing the segments of 1
+// * 5 is the five-segment digit fully contained in 6
+//
+// Returns `None` unless the patterns are ten distinct digits and the output is
+// made of them.
+fn decode(entry: &Entry) -> Option<i32> {
+    let patterns = entry
+        .patterns
+        .split_whitespace()
+        .map(segments)
+        .collect::<Vec<u8>>();
+    let find = |len: u32| patterns.iter().copied().find(|p| p.count_ones() == len);
+    let one = find(2)?;
+    let four = find(4)?;
+    let mut digits = [0u8; 10];
+    digits[1] = one;
+    digits[4] = four;
+    digits[7] = find(3)?;
+    digits[8] = find(7)?;
+    for &p in patterns.iter().filter(|p| p.count_ones() == 6) {
+        if p & four == four {
+            digits[9] = p;
+        } else if p & one == one {
+            digits[0] = p;
+        } else {
+            digits[6] = p;
+        }
+    }
+    for &p in patterns.iter().filter(|p| p.count_ones() == 5) {
+        if p & one == one {
+            digits[3] = p;
+        } else if p & digits[6] == p {
+            digits[5] = p;
+        } else {
+            digits[2] = p;
+        }
+    }
+    let mut sorted = digits;
+    sorted.sort_unstable();
+    if sorted[0] == 0 || sorted.windows(2).any(|w| w[0] == w[1]) {
+        return None;
+    }
+    entry
+        .output
+        .split_whitespace()
+        .map(segments)
+        .map(|s| digits.iter().position(|&d| d == s).map(|d| d as i32))
+        .try_fold(0, |value, digit| Some(value * 10 + digit?))
+}
+
+/// The segments of each digit, with the wires connected as expected.
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Generates `size` entries, each display being wired at random.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut wires = "abcdefg".chars().collect::<Vec<char>>();
+        rng.shuffle(&mut wires);
+        let pattern = |rng: &mut Rng, digit: usize| {
+            let mut segments = DIGIT_SEGMENTS[digit]
+                .chars()
+                .map(|c| wires[c as usize - 'a' as usize])
+                .collect::<Vec<char>>();
+            rng.shuffle(&mut segments);
+            String::from_iter(segments)
+        };
+        let mut digits = (0..10).collect::<Vec<usize>>();
+        let patterns = digits
+            .iter()
+            .map(|&d| pattern(rng, d))
+            .collect::<Vec<String>>();
+        let output = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                pattern(rng, digit)
+            })
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut digits);
+        let patterns = digits
+            .iter()
+            .map(|&d| patterns[d].as_str())
+            .collect::<Vec<&str>>();
+        input.push_str(&format!("{} | {}\n", patterns.join(" "), output.join(" ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str =
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga |gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf |gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf |cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg |gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |fgae cfgab fg bagce";
+
+    #[test]
+    fn test_day08_parta_sample() {
+        let input = parse_input(RAW_INPUT).unwrap();
+        let res = process_a(&input);
+        assert_eq!(res, 26);
+    }
+
+    #[test]
+    fn test_day08_partb_sample() {
+        let input = parse_input(RAW_INPUT).unwrap();
+        let res = process_b(&input);
+        assert_eq!(res, 61229);
+    }
+
+    #[test]
+    fn test_day08_parse_errors() {
+        let entry = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe";
+        // Lines without a `|` are skipped.
+        let input = format!("{}\nccccjddttcdknbdgccbbfbdjntdgbdglvhhdgrfjfc\n", entry);
+        assert_eq!(parse_input(&input).unwrap().len(), 1);
+        assert!(parse_input("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb").is_err());
+        let error = parse_input(&format!(
+            "{}\nbe cfbegad | fdgacbe cefdb cefbgd gcbe",
+            entry
+        ));
+        assert_eq!(error.unwrap_err().line(), Some(2));
+        assert!(parse_input("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbx").is_err());
+        // No pattern of two segments.
+        assert!(parse_input("bef cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe").is_err());
+        // A pattern twice.
+        assert!(parse_input("be cfbegad cbdgef cbdgef cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe").is_err());
+        // An output digit which is not among the patterns.
+        let error = parse_input("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd abcd").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected the patterns of the ten digits"));
+    }
+}
@@ -0,0 +1,138 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{Answer, AocError, Grid, Part, Registry, Solution};
+use std::collections::HashSet;
+
+pub type Heightmap = Grid<u32>;
+
+pub fn is_low_point(hm: &Heightmap, x: usize, y: usize) -> bool {
+    let height = hm[(x, y)];
+    hm.neighbors4(x, y).all(|neighbor| hm[neighbor] > height)
+}
+
+/// Returns the low points with their risk level.
+pub fn find_low_points(hm: &Heightmap) -> Vec<((usize, usize), u32)> {
+    hm.positions()
+        .filter(|&((x, y), _)| is_low_point(hm, x, y))
+        .map(|(point, height)| (point, height + 1))
+        .collect()
+}
+
+pub fn risk_level(hm: &Heightmap) -> u32 {
+    find_low_points(hm).iter().map(|p| p.1).sum::<u32>()
+}
+
+/// Counts the locations flowing down to the low point at `low_point`.
+///
+/// Locations of height 9 do not count as being in any basin.
+pub fn find_bassin_size(hm: &Heightmap, low_point: (usize, usize)) -> u32 {
+    let mut bassin: HashSet<(usize, usize)> = HashSet::new();
+    let mut to_visit = vec![low_point];
+    while let Some(current) = to_visit.pop() {
+        if !bassin.insert(current) {
+            continue;
+        }
+        let value = hm[current];
+        to_visit.extend(
+            hm.neighbors4(current.0, current.1)
+                .filter(|&p| hm[p] >= value && hm[p] < 9),
+        );
+    }
+    bassin.len() as u32
+}
+
+pub fn largest_bassins(hm: &Heightmap) -> u32 {
+    let mut sizes = find_low_points(hm)
+        .iter()
+        .map(|p| find_bassin_size(hm, p.0))
+        .collect::<Vec<u32>>();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        9,
+        Part::A,
+        "Smoke Basin",
+        Heightmap::from_digits,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        9,
+        Part::B,
+        "Smoke Basin",
+        Heightmap::from_digits,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    Heightmap::from_digits(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    Heightmap::from_digits(input).map(part_b)
+}
+
+pub fn day09a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(9)?)?.to_string())
+}
+
+pub fn day09b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(9)?)?.to_string())
+}
+
+fn part_a(heightmap: Heightmap) -> Answer {
+    risk_level(&heightmap).into()
+}
+
+fn part_b(heightmap: Heightmap) -> Answer {
+    largest_bassins(&heightmap).into()
+}
+
+/// Generates a heightmap of `size` by `size` locations.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row = (0..size)
+                .map(|_| rng.range(0..=9).to_string())
+                .collect::<String>();
+            format!("{}\n", row)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_day09_parta_sample() {
+        let heightmap = Heightmap::from_digits(RAW_INPUT).unwrap();
+        let risk_level = risk_level(&heightmap);
+        assert_eq!(risk_level, 15);
+    }
+
+    #[test]
+    fn test_day09_partb_sample() {
+        let heightmap = Heightmap::from_digits(RAW_INPUT).unwrap();
+        assert_eq!(largest_bassins(&heightmap), 1134);
+    }
+
+    #[test]
+    fn test_day09_parse_errors() {
+        let error = Heightmap::from_digits("2199943210\n398789492").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: inconsistent row length: `398789492`"
+        );
+        assert!(Heightmap::from_digits("21999a3210").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, Answer, AocError, Part, Registry, Solution};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        10,
+        Part::A,
+        "Syntax Scoring",
+        read_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        10,
+        Part::B,
+        "Syntax Scoring",
+        read_incomplete_input,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    read_incomplete_input(input).map(part_b)
+}
+
+pub fn day10a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(10)?)?.to_string())
+}
+
+pub fn day10b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(10)?)?.to_string())
+}
+
+fn part_a(lines: Vec<Chunks>) -> Answer {
+    process_lines(&lines).into()
+}
+
+fn part_b(lines: Vec<Chunks>) -> Answer {
+    repair_lines(&lines).into()
+}
+
+/// A line read up to its first illegal character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Chunks {
+    /// The line closes a chunk with the wrong character.
+    Corrupted(char),
+    /// The openings of the chunks left open, the innermost last.
+    Incomplete(Vec<char>),
+}
+
+pub fn read_input(input: &str) -> Result<Vec<Chunks>, AocError> {
+    parse_lines(input, read_chunks)
+}
+
+/// Reads the lines, requiring at least one incomplete line to repair, and
+/// every incomplete line to have a score.
+fn read_incomplete_input(input: &str) -> Result<Vec<Chunks>, AocError> {
+    let lines = parse_lines(input, |line| {
+        let chunks = read_chunks(line)?;
+        match chunks {
+            Chunks::Incomplete(_) if repair_line(&chunks).is_none() => Err(AocError::invalid(
+                line,
+                "too many chunks left open to score their completion",
+            )),
+            _ => Ok(chunks),
+        }
+    })?;
+    match lines.iter().any(|l| matches!(l, Chunks::Incomplete(_))) {
+        true => Ok(lines),
+        false => Err(AocError::new("no incomplete line to repair")),
+    }
+}
+
+fn closing(opening: char) -> Option<char> {
+    match opening {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+pub fn read_chunks(line: &str) -> Result<Chunks, AocError> {
+    let mut stack: Vec<char> = Vec::new();
+    for c in line.chars() {
+        if closing(c).is_some() {
+            stack.push(c);
+        } else if !")]}>".contains(c) {
+            return Err(AocError::invalid(line, "expected a line of brackets"));
+        } else if let Some(opening) = stack.pop() {
+            if closing(opening) != Some(c) {
+                return Ok(Chunks::Corrupted(c));
+            }
+        } else {
+            return Err(AocError::invalid(
+                line,
+                format!("`{}` closes a chunk which was never opened", c),
+            ));
+        }
+    }
+    Ok(Chunks::Incomplete(stack))
+}
+
+pub fn process_line(line: &Chunks) -> u32 {
+    match line {
+        Chunks::Corrupted(')') => 3,
+        Chunks::Corrupted(']') => 57,
+        Chunks::Corrupted('}') => 1197,
+        Chunks::Corrupted(_) => 25137,
+        Chunks::Incomplete(_) => 0,
+    }
+}
+
+pub fn process_lines(lines: &[Chunks]) -> u32 {
+    lines.iter().map(process_line).sum()
+}
+
+/// Returns the score of the characters completing an incomplete line, unless
+/// it is too large for a `u64`.
+pub fn repair_line(line: &Chunks) -> Option<u64> {
+    let Chunks::Incomplete(stack) = line else {
+        return None;
+    };
+    stack.iter().rev().try_fold(0u64, |score, opening| {
+        let points = match opening {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            _ => 4,
+        };
+        score.checked_mul(5)?.checked_add(points)
+    })
+}
+
+/// Returns the middle score of the incomplete lines, 0 if there is none.
+pub fn repair_lines(lines: &[Chunks]) -> u64 {
+    let mut scores = lines.iter().filter_map(repair_line).collect::<Vec<u64>>();
+    scores.sort_unstable();
+    scores.get(scores.len() / 2).copied().unwrap_or(0)
+}
+
+/// Generates `size` lines of brackets, an odd number of them being
+/// incomplete and the others corrupted.
+///
+/// Chunks are nested at most twelve deep, so that the completion scores fit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let incomplete = (size / 2) | 1;
+    let mut kinds = (0..size).map(|i| i < incomplete).collect::<Vec<bool>>();
+    rng.shuffle(&mut kinds);
+    let mut input = String::new();
+    for is_incomplete in kinds {
+        let mut line = String::new();
+        let mut stack: Vec<usize> = Vec::new();
+        for _ in 0..rng.range(20..=100) {
+            match stack.last() {
+                Some(_) if stack.len() >= 12 || rng.one_in(2) => {
+                    line.push(PAIRS[stack.pop().unwrap()].1)
+                }
+                _ => {
+                    let pair = rng.below(PAIRS.len());
+                    stack.push(pair);
+                    line.push(PAIRS[pair].0);
+                }
+            }
+        }
+        if !is_incomplete {
+            // Close the last chunk with the wrong character.
+            let expected = *stack.last().unwrap_or(&0);
+            let wrong = (expected + 1 + rng.below(PAIRS.len() - 1)) % PAIRS.len();
+            if stack.is_empty() {
+                line.push(PAIRS[expected].0);
+            }
+            line.push(PAIRS[wrong].1);
+        } else if stack.is_empty() {
+            line.push(PAIRS[rng.below(PAIRS.len())].0);
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn test_day10_parta_sample() {
+        let input = read_input(RAW_INPUT).unwrap();
+        assert_eq!(process_lines(&input), 26397);
+    }
+
+    #[test]
+    fn test_day10_partb_sample() {
+        let input = read_input(RAW_INPUT).unwrap();
+        assert_eq!(repair_lines(&input), 288957);
+    }
+
+    #[test]
+    fn test_repair_line() {
+        let line = "<{([{{}}[<[[[<>{}]]]>[]]";
+        let score = repair_line(&read_chunks(line).unwrap()).unwrap();
+        assert_eq!(score, 294);
+    }
+
+    #[test]
+    fn test_day10_parse_errors() {
+        let error = read_input("[<>]\n(]\n())").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: `)` closes a chunk which was never opened: `())`"
+        );
+        assert!(read_input("[<x>]").is_err());
+        assert_eq!(process_lines(&read_input("(]\n{)").unwrap()), 57 + 3);
+        assert!(read_incomplete_input("").is_err());
+        assert!(read_incomplete_input("(]").is_err());
+        let error = read_incomplete_input(&format!("()\n{}", "<".repeat(28))).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2: too many chunks left open to score their completion"));
+        assert!(read_incomplete_input(&"<".repeat(27)).is_ok());
+        assert_eq!(solve_b(RAW_INPUT), Ok(Answer::Number(288957)));
+    }
+}
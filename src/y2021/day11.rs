@@ -0,0 +1,262 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{debug, info, parse_lines, Answer, AocError, Grid, Part, Registry, Solution};
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct Octopus {
+    energy_level: u32,
+}
+
+impl Octopus {
+    pub fn new(energy_level: u32) -> Self {
+        Octopus { energy_level }
+    }
+
+    /// inc returns True if an Octopus flashes.
+    pub fn inc(&mut self) {
+        self.energy_level += 1;
+    }
+
+    pub fn flashed(&self) -> bool {
+        matches!(self.energy_level, 10)
+    }
+
+    pub fn reset(&mut self) {
+        if self.energy_level > 9 {
+            self.energy_level = 0;
+        }
+    }
+}
+
+impl fmt::Display for Octopus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.energy_level)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        11,
+        Part::A,
+        "Dumbo Octopus",
+        read_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        11,
+        Part::B,
+        "Dumbo Octopus",
+        read_input,
+        part_b,
+    ));
+}
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 11,
+        name: "flashes",
+        help: "Count the flashes in a number of cycles, up to the first synchronized flash",
+        param: ("cycles", 100),
+        run: |input, cycles| {
+            let mut map = read_input(input)?;
+            let (flashes, synced) = flash_cycles(&mut map, probe_cycles(cycles)?);
+            Ok(match synced {
+                Some(cycle) => format!("{} (synchronized during cycle {})", flashes, cycle),
+                None => flashes.to_string(),
+            })
+        },
+    });
+    probes.register(Probe {
+        day: 11,
+        name: "map",
+        help: "Print the energy levels after a number of cycles",
+        param: ("cycles", 0),
+        run: |input, cycles| {
+            let mut map = read_input(input)?;
+            for _ in 0..cycles {
+                flash_cycle(&mut map);
+            }
+            Ok(map.to_string())
+        },
+    });
+}
+
+fn probe_cycles(cycles: usize) -> Result<u32, AocError> {
+    u32::try_from(cycles).map_err(|_| AocError::new(format!("too many cycles: {}", cycles)))
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    read_input(input).map(part_b)
+}
+
+pub fn day11a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(11)?)?.to_string())
+}
+
+pub fn day11b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(11)?)?.to_string())
+}
+
+fn part_a(mut map: Grid<Octopus>) -> Answer {
+    let (flashes, _synced_cycle) = flash_cycles(&mut map, 100);
+    flashes.into()
+}
+
+fn part_b(mut map: Grid<Octopus>) -> Answer {
+    let (_flashes, synced_cycle) = flash_cycles(&mut map, 1000);
+    match synced_cycle {
+        None => "No synced cycle".into(),
+        Some(c) => c.into(),
+    }
+}
+
+pub fn flash(map: &mut Grid<Octopus>, x: usize, y: usize) -> i64 {
+    let mut flashes = 1;
+    for (nx, ny) in map.neighbors8(x, y).collect::<Vec<_>>() {
+        let octopus = &mut map[(nx, ny)];
+        octopus.inc();
+        if octopus.flashed() {
+            flashes += flash(map, nx, ny);
+        }
+    }
+    flashes
+}
+
+/// Runs a single cycle, returning the number of flashes.
+pub fn flash_cycle(map: &mut Grid<Octopus>) -> i64 {
+    let mut flashes: i64 = 0;
+    for index in 0..map.len() {
+        map[index].inc();
+        if map[index].flashed() {
+            let (x, y) = map.coords_of(index).unwrap();
+            flashes += flash(map, x, y)
+        }
+    }
+
+    // Reset the octopuses that flashed.
+    map.iter_mut().for_each(Octopus::reset);
+    flashes
+}
+
+/// Runs up to `cycles` cycles, stopping at the first synchronized flash.
+pub fn flash_cycles(map: &mut Grid<Octopus>, cycles: u32) -> (i64, Option<u32>) {
+    let mut flashes: i64 = 0;
+    let mut first_flash: Option<u32> = None;
+
+    for cycle in 1..=cycles {
+        flashes += flash_cycle(map);
+
+        if map.iter().all(|octopus| octopus.energy_level == 0) {
+            info!("synchronized flash during cycle #{}", cycle);
+            if first_flash.is_none() {
+                first_flash = Some(cycle);
+                return (flashes, first_flash);
+            }
+        }
+    }
+
+    (flashes, first_flash)
+}
+
+pub fn read_input(input: &str) -> Result<Grid<Octopus>, AocError> {
+    let rows = parse_lines(input, |line| {
+        line.chars()
+            .map(|c| c.to_digit(10).map(Octopus::new))
+            .collect::<Option<Vec<Octopus>>>()
+            .ok_or_else(|| AocError::invalid(line, "expected a row of energy levels"))
+    })?;
+    match rows.is_empty() {
+        true => Err(AocError::new("no octopus")),
+        false => Grid::from_rows(rows),
+    }
+}
+
+/// Logs the energy levels at the debug level.
+pub fn print_map(map: &Grid<Octopus>) {
+    debug!("\n{}", map)
+}
+
+/// Generates the energy levels of `size` by `size` octopuses.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row = (0..size)
+                .map(|_| rng.range(0..=9).to_string())
+                .collect::<String>();
+            format!("{}\n", row)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn test_day11_parta_sample() {
+        let mut map: Grid<Octopus> = read_input(RAW_INPUT).unwrap();
+        let (flashes, _) = flash_cycles(&mut map, 100);
+        assert_eq!(flashes, 1656);
+    }
+
+    #[test]
+    fn test_day11_partb_sample() {
+        let mut map: Grid<Octopus> = read_input(RAW_INPUT).unwrap();
+        let (_, synced_cycle) = flash_cycles(&mut map, 200);
+        assert!(synced_cycle.is_some());
+        assert_eq!(synced_cycle.unwrap(), 195);
+    }
+
+    #[test]
+    fn test_day11_map_probe() {
+        let mut probes = Probes::new();
+        register_probes(&mut probes);
+        let map = probes.get(11, "map").unwrap().run;
+        let synced = "0000000000\n".repeat(10);
+        assert_eq!(map(RAW_INPUT, 195).unwrap().trim_end(), synced.trim_end());
+        // The octopuses go on after flashing together.
+        let after = "1111111111\n".repeat(10);
+        assert_eq!(map(RAW_INPUT, 196).unwrap().trim_end(), after.trim_end());
+    }
+
+    #[test]
+    fn test_day11_flashes() {
+        let input: &str = "11111
+19991
+19191
+19991
+11111";
+        let mut map: Grid<Octopus> = read_input(input).unwrap();
+        print_map(&map);
+        let (flashes, _) = flash_cycles(&mut map, 1);
+        print_map(&map);
+        assert_eq!(flashes, 9);
+    }
+
+    #[test]
+    fn test_day11_parse_errors() {
+        let error = read_input("11111\n19x91").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of energy levels: `19x91`"
+        );
+    }
+}
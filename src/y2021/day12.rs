@@ -0,0 +1,291 @@
+// I used this puzzle more for learning this time.
+//
+// I got some inspiration from:
+// * https://github.com/seamusriordan/advent-of-code-2021-day-12-rust/blob/part2/src/lib.rs
+// * https://github.com/kamioftea/advent-of-code-2021/blob/main/src/day_12.rs
+//
+// Graph theory:
+// * https://github.com/nrc/r4cppp/blob/master/graphs/README.md
+// * https://depth-first.com/articles/2020/02/03/graphs-in-rust-an-introduction-to-petgraph/
+//
+use super::read_asset;
+use crate::generate::Rng;
+use crate::{parse_lines, Answer, AocError, Graph, NodeId, Part, Registry, Solution};
+
+#[derive(Debug, PartialEq)]
+pub enum CaveType {
+    Start,
+    End,
+    Small,
+    Large,
+}
+
+// https://doc.rust-lang.org/rust-by-example/conversion/from_into.html
+impl From<&str> for CaveType {
+    fn from(item: &str) -> Self {
+        match item {
+            "start" => CaveType::Start,
+            "end" => CaveType::End,
+            label if label.to_uppercase() == label => CaveType::Large,
+            _ => CaveType::Small,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cave {
+    label: String,
+    cave_type: CaveType,
+}
+
+impl From<&str> for Cave {
+    fn from(item: &str) -> Self {
+        Cave {
+            label: item.into(),
+            cave_type: CaveType::from(item),
+        }
+    }
+}
+
+/// The cave system, the passages going both ways.
+pub type Caves = Graph<Cave>;
+
+pub fn parse_input(input: &str) -> Result<Caves, AocError> {
+    let connections = parse_lines(input, |line| {
+        let (from, to) = line
+            .split_once('-')
+            .filter(|(from, to)| !from.is_empty() && !to.is_empty())
+            .ok_or_else(|| AocError::invalid(line, "expected a connection formatted as `a-b`"))?;
+        match (CaveType::from(from), CaveType::from(to)) {
+            (CaveType::Large, CaveType::Large) => Err(AocError::invalid(
+                line,
+                "two big caves are connected, which makes for infinitely many paths",
+            )),
+            _ => Ok((from, to)),
+        }
+    })?;
+    let mut caves = Caves::new();
+    for (from, to) in connections {
+        let from = find_or_add_cave(&mut caves, from);
+        let to = find_or_add_cave(&mut caves, to);
+        caves.add_undirected_edge(from, to, ());
+    }
+    Ok(caves)
+}
+
+fn find_or_add_cave(caves: &mut Caves, label: &str) -> NodeId {
+    caves
+        .find(|cave| cave.label == label)
+        .unwrap_or_else(|| caves.add_node(Cave::from(label)))
+}
+
+/// Counts the paths from `start` to `end`.
+///
+/// Small caves can be visited at most once, except for a single one which can
+/// be visited twice when `allow_twice` is set.
+pub fn count_paths(caves: &Caves, allow_twice: bool) -> usize {
+    let start = caves.find(|cave| cave.cave_type == CaveType::Start);
+    let end = caves.find(|cave| cave.cave_type == CaveType::End);
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return 0,
+    };
+    caves.count_paths(start, end, |path, next| match caves.node(next).cave_type {
+        CaveType::Start => false,
+        CaveType::Small if path.contains(&next) => allow_twice && !visited_twice(caves, path),
+        _ => true,
+    })
+}
+
+/// Tells whether a small cave has already been visited twice along `path`.
+fn visited_twice(caves: &Caves, path: &[NodeId]) -> bool {
+    path.iter()
+        .enumerate()
+        .any(|(i, id)| caves.node(*id).cave_type == CaveType::Small && path[i + 1..].contains(id))
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        12,
+        Part::A,
+        "Passage Pathing",
+        parse_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        12,
+        Part::B,
+        "Passage Pathing",
+        parse_input,
+        part_b,
+    ));
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_b)
+}
+
+pub fn day12a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(12)?)?.to_string())
+}
+
+pub fn day12b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(12)?)?.to_string())
+}
+
+fn part_a(caves: Caves) -> Answer {
+    count_paths(&caves, false).into()
+}
+
+fn part_b(caves: Caves) -> Answer {
+    count_paths(&caves, true).into()
+}
+
+/// Generates a cave system with `size` small caves.
+///
+/// A path from `start` to `end` through a few caves is laid first, so that
+/// there is always at least one. Big caves are never connected to each other, which would make for
+/// infinitely many paths. The number of paths grows quickly with the size.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let small = (0..)
+        .map(|i| cave_name(i, b'a'))
+        .filter(|name| name != "start" && name != "end")
+        .take(size)
+        .collect::<Vec<String>>();
+    let big = (0..(size / 4).max(1))
+        .map(|i| cave_name(i, b'A'))
+        .collect::<Vec<String>>();
+    let caves = small.iter().chain(big.iter()).collect::<Vec<&String>>();
+
+    let mut path = vec!["start"];
+    for _ in 0..rng.range(1..=3) {
+        let last = *path.last().unwrap();
+        let last_is_big = big.iter().any(|cave| cave == last);
+        let next = match small.is_empty() || (!last_is_big && rng.one_in(3)) {
+            true => rng.choose(&big),
+            false => rng.choose(&small),
+        };
+        if next != last {
+            path.push(next);
+        }
+    }
+    path.push("end");
+    let mut connections = path
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect::<Vec<(&str, &str)>>();
+    for cave in small.iter() {
+        for _ in 0..rng.range(1..=2) {
+            let other = *rng.choose(&caves);
+            if other != cave {
+                connections.push((cave.as_str(), other.as_str()));
+            }
+        }
+    }
+    for end in ["start", "end"] {
+        for _ in 0..2 {
+            connections.push((end, rng.choose(&caves).as_str()));
+        }
+    }
+    connections.sort_unstable_by_key(|&(a, b)| (a.min(b), a.max(b)));
+    connections.dedup_by_key(|&mut (a, b)| (a.min(b), a.max(b)));
+    rng.shuffle(&mut connections);
+    connections
+        .into_iter()
+        .map(|(a, b)| match rng.one_in(2) {
+            true => format!("{}-{}\n", a, b),
+            false => format!("{}-{}\n", b, a),
+        })
+        .collect()
+}
+
+/// Names the caves with letters from `first`: `aa`, `ab`... `zz`, `aaa`...
+fn cave_name(mut i: usize, first: u8) -> String {
+    let mut len = 2;
+    while let Some(count) = 26usize.checked_pow(len).filter(|&count| i >= count) {
+        i -= count;
+        len += 1;
+    }
+    (0..len)
+        .rev()
+        .map(|digit| (first + (i / 26usize.pow(digit) % 26) as u8) as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    #[test]
+    fn test_day12_parta_sample() {
+        let input = parse_input(RAW_INPUT).unwrap();
+        assert_eq!(input.len(), 6);
+        assert_eq!(count_paths(&input, false), 10);
+    }
+
+    #[test]
+    fn test_day12_partb_sample() {
+        let input = parse_input(RAW_INPUT).unwrap();
+        assert_eq!(count_paths(&input, true), 36);
+    }
+
+    #[test]
+    fn test_day12_parse_errors() {
+        let error = parse_input("start-A\nA c").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a connection formatted as `a-b`: `A c`"
+        );
+        assert!(parse_input("start-").is_err());
+        let error = parse_input("start-A\nA-B\nB-end").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: two big caves are connected, which makes for infinitely many paths: `A-B`"
+        );
+        assert!(parse_input("start-A\nA-A\nA-end").is_err());
+    }
+
+    #[test]
+    fn test_day12_generate() {
+        assert_eq!(cave_name(0, b'a'), "aa");
+        assert_eq!(cave_name(675, b'A'), "ZZ");
+        assert_eq!(cave_name(676, b'a'), "aaa");
+        assert_eq!(cave_name(676 + 26 * 26 * 26, b'a'), "aaaa");
+        // Enough caves to need three letters, and to name one `end`.
+        let input = generate(&mut Rng::new(1), 4000);
+        let caves = parse_input(&input).unwrap();
+        let labels = input
+            .lines()
+            .flat_map(|line| line.split('-'))
+            .collect::<std::collections::HashSet<&str>>();
+        assert!(labels
+            .iter()
+            .all(|label| label.chars().all(|c| c.is_ascii_alphabetic())));
+        assert!(labels.contains("aaa"));
+        assert_eq!(caves.len(), labels.len());
+        // Only the path laid first and the two connections generated for the
+        // end lead to it.
+        let to_end = input
+            .lines()
+            .filter(|line| line.split('-').any(|c| c == "end"));
+        assert!(to_end.count() <= 3);
+
+        for seed in 0..50 {
+            let caves = parse_input(&generate(&mut Rng::new(seed), 8)).unwrap();
+            assert!(count_paths(&caves, false) > 0, "no path with seed {}", seed);
+        }
+    }
+}
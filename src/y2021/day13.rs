@@ -0,0 +1,261 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{Answer, AocError, Part, Point, Registry, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+impl TryFrom<&str> for Axis {
+    type Error = AocError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        match item {
+            "x" => Ok(Axis::X),
+            "y" => Ok(Axis::Y),
+            _ => Err(AocError::invalid(item, "unknown axis")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Fold {
+    axis: Axis,
+    value: i32,
+}
+
+impl FromStr for Fold {
+    type Err = AocError;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^fold along ([a-z])=(\d{1,5})$").unwrap();
+        let caps = re.captures(item).ok_or_else(|| {
+            AocError::invalid(item, "expected a fold formatted as `fold along x=N`")
+        })?;
+        Ok(Fold {
+            axis: Axis::try_from(&caps[1])?,
+            value: caps[2]
+                .parse::<i32>()
+                .map_err(|e| AocError::invalid(item, format!("invalid fold value: {}", e)))?,
+        })
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        13,
+        Part::A,
+        "Transparent Origami",
+        parse_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        13,
+        Part::B,
+        "Transparent Origami",
+        parse_input,
+        part_b,
+    ));
+}
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 13,
+        name: "paper",
+        help: "Print the paper after a number of folds",
+        param: ("folds", 1),
+        run: |input, folds| {
+            let (points, instructions) = parse_input(input)?;
+            Ok(render_paper(&fold(&points, &instructions, folds)))
+        },
+    });
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_b)
+}
+
+pub fn day13a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(13)?)?.to_string())
+}
+
+pub fn day13b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(13)?)?.to_string())
+}
+
+fn part_a((points, folds): (Vec<Point>, Vec<Fold>)) -> Answer {
+    fold_paper(&points, &folds, 1).into()
+}
+
+fn part_b((points, folds): (Vec<Point>, Vec<Fold>)) -> Answer {
+    render_paper(&fold(&points, &folds, folds.len())).into()
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Fold>), AocError> {
+    let mut points = Vec::new();
+    let mut folds = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let parsed = match line.starts_with("fold") {
+            true => line.parse::<Fold>().map(|f| folds.push(f)),
+            false => line.parse::<Point>().map(|p| points.push(p)),
+        };
+        parsed.map_err(|e| e.with_line(i + 1))?;
+    }
+    Ok((points, folds))
+}
+
+pub fn fold(points: &[Point], folds: &[Fold], fold_count: usize) -> HashSet<Point> {
+    let mut folded_points: Vec<Point> = points.to_vec();
+    for fold in folds.iter().take(fold_count) {
+        // dbg!(&fold);
+        for point in folded_points.iter_mut() {
+            match fold.axis {
+                Axis::X => {
+                    if point.x() > fold.value {
+                        *point = Point::new(2 * fold.value - point.x(), point.y());
+                    }
+                }
+                Axis::Y => {
+                    if point.y() > fold.value {
+                        *point = Point::new(point.x(), 2 * fold.value - point.y());
+                    }
+                }
+            }
+        }
+        // dbg!(&folded_points);
+    }
+    folded_points.into_iter().collect::<HashSet<Point>>()
+}
+
+pub fn fold_paper(points: &[Point], folds: &[Fold], fold_count: usize) -> usize {
+    // Return the nuumber of points.
+    fold(points, folds, fold_count).len()
+}
+
+/// Prints the paper, using `#` for the dots and `.` for the blanks.
+pub fn render_paper(point_set: &HashSet<Point>) -> String {
+    let (_, max) = Point::bounding_box(point_set).unwrap_or_default();
+    let mut paper = String::new();
+    for y in 0..=max.y() {
+        if y > 0 {
+            paper.push('\n');
+        }
+        for x in 0..=max.x() {
+            if point_set.contains(&Point::new(x, y)) {
+                paper.push('#');
+            } else {
+                paper.push('.');
+            }
+        }
+    }
+    paper
+}
+
+/// Generates `size` dots and twelve folds.
+///
+/// The folds are made up by unfolding a small sheet, so that they always fold
+/// the paper in half and no dot ever lies on a fold line.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut width, mut height) = (5, 6);
+    let mut folds = Vec::new();
+    for _ in 0..12 {
+        match rng.one_in(2) {
+            true => {
+                folds.push((Axis::X, width));
+                width = 2 * width + 1;
+            }
+            false => {
+                folds.push((Axis::Y, height));
+                height = 2 * height + 1;
+            }
+        }
+    }
+    let mut input = String::new();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.range(0..=4), rng.range(0..=5));
+        for (axis, value) in folds.iter() {
+            if rng.one_in(2) {
+                match axis {
+                    Axis::X => x = 2 * value - x,
+                    Axis::Y => y = 2 * value - y,
+                }
+            }
+        }
+        input.push_str(&format!("{},{}\n", x, y));
+    }
+    input.push('\n');
+    for (axis, value) in folds.iter().rev() {
+        let axis = match axis {
+            Axis::X => 'x',
+            Axis::Y => 'y',
+        };
+        input.push_str(&format!("fold along {}={}\n", axis, value));
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RAW_INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn test_day13_parta_sample() {
+        let (points, folds) = parse_input(RAW_INPUT).unwrap();
+        assert_eq!(points.len(), 18);
+        let point_count = fold_paper(&points, &folds, 2);
+        assert_eq!(point_count, 16);
+    }
+
+    #[test]
+    fn test_day13_partb_sample() {
+        let (points, folds) = parse_input(RAW_INPUT).unwrap();
+        let paper = render_paper(&fold(&points, &folds, folds.len()));
+        assert_eq!(paper, "#####\n#...#\n#...#\n#...#\n#####");
+    }
+
+    #[test]
+    fn test_day13_parse_errors() {
+        let error = parse_input("6,10\n\nfold along z=7").unwrap_err();
+        assert_eq!(error.to_string(), "line 3: unknown axis: `z`");
+        assert!(parse_input("6;10").is_err());
+        assert!(parse_input("fold along y=").is_err());
+    }
+}
@@ -0,0 +1,383 @@
+use super::read_asset;
+use crate::generate::Rng;
+use crate::repl::{Probe, Probes};
+use crate::{trace, Answer, AocError, Part, Registry, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Solution::new(
+        14,
+        Part::A,
+        "Extended Polymerization",
+        parse_input,
+        part_a,
+    ));
+    registry.register(Solution::new(
+        14,
+        Part::B,
+        "Extended Polymerization",
+        parse_countable_input,
+        part_b,
+    ));
+}
+
+/// The number of steps of part B.
+const STEPS_B: usize = 40;
+
+/// The longest polymer the `polymer` probe builds.
+const MAX_POLYMER_LEN: usize = 1_000_000;
+
+/// The most steps the `count` probe runs, which bounds its recursion. Most
+/// rules give more elements than an `i64` can count long before.
+const MAX_COUNT_STEPS: usize = 1000;
+
+pub fn register_probes(probes: &mut Probes) {
+    probes.register(Probe {
+        day: 14,
+        name: "count",
+        help: "Count the elements of the polymer after a number of steps",
+        param: ("steps", 10),
+        run: |input, steps| {
+            let (template, rules) = parse_input(input)?;
+            let counts = match steps <= MAX_COUNT_STEPS {
+                true => memoized_polymerization(&template, &rules, steps),
+                false => None,
+            }
+            .ok_or_else(|| {
+                AocError::new(format!("too many elements to count after {} steps", steps))
+            })?;
+            Ok(counts
+                .into_iter()
+                .sorted()
+                .map(|(c, count)| format!("{}: {}", c, count))
+                .join("\n"))
+        },
+    });
+    probes.register(Probe {
+        day: 14,
+        name: "polymer",
+        help: "Print the polymer after a number of steps",
+        param: ("steps", 0),
+        run: |input, steps| {
+            let (template, rules) = parse_input(input)?;
+            // The polymer roughly doubles in length at each step.
+            let len = u32::try_from(steps)
+                .ok()
+                .and_then(|steps| 2usize.checked_pow(steps))
+                .and_then(|factor| factor.checked_mul(template.len()));
+            match len {
+                Some(len) if len <= MAX_POLYMER_LEN => {
+                    Ok(String::from_iter(polymerization(&template, &rules, steps)))
+                }
+                _ => Err(AocError::new(format!(
+                    "the polymer would be too long after {} steps, try `count`",
+                    steps
+                ))),
+            }
+        },
+    });
+}
+
+pub fn solve_a(input: &str) -> Result<Answer, AocError> {
+    parse_input(input).map(part_a)
+}
+
+pub fn solve_b(input: &str) -> Result<Answer, AocError> {
+    parse_countable_input(input).map(part_b)
+}
+
+pub fn day14a() -> Result<String, AocError> {
+    Ok(solve_a(&read_asset(14)?)?.to_string())
+}
+
+pub fn day14b() -> Result<String, AocError> {
+    Ok(solve_b(&read_asset(14)?)?.to_string())
+}
+
+fn part_a((polymer_template, rules): (String, HashMap<String, String>)) -> Answer {
+    let polymer = polymerization(&polymer_template, &rules, 10);
+    let (most, least) = most_least_char(frequency(polymer));
+    (most - least).into()
+}
+
+fn part_b((polymer_template, rules): (String, HashMap<String, String>)) -> Answer {
+    let freq = memoized_polymerization(&polymer_template, &rules, STEPS_B);
+    let (most, least) = most_least_char(freq.expect("checked when parsing"));
+    (most - least).into()
+}
+
+/// Parses the input, requiring that the elements can be counted after the
+/// steps of part B.
+fn parse_countable_input(input: &str) -> Result<(String, HashMap<String, String>), AocError> {
+    let (polymer_template, rules) = parse_input(input)?;
+    // Each step inserts at most one element between each pair.
+    let pairs = polymer_template.chars().count() as i64 - 1;
+    match pairs.checked_mul(1 << STEPS_B) {
+        Some(_) => Ok((polymer_template, rules)),
+        None => Err(AocError::new(format!(
+            "the polymer template is too long to count its elements after {} steps",
+            STEPS_B
+        ))),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(String, HashMap<String, String>), AocError> {
+    let mut lines = input.trim().lines().enumerate();
+    let polymer_template = match lines.next() {
+        Some((_, template)) if !template.trim().is_empty() => template.trim().to_string(),
+        _ => return Err(AocError::new("missing polymer template")),
+    };
+    let mut rules = HashMap::new();
+    for (i, rule) in lines {
+        let rule = rule.trim();
+        if rule.is_empty() {
+            continue;
+        }
+        match rule.split_once(" -> ") {
+            Some((pair, insertion)) if pair.len() == 2 && insertion.len() == 1 => {
+                rules.insert(pair.to_string(), insertion.to_string());
+            }
+            _ => {
+                return Err(
+                    AocError::invalid(rule, "expected a rule formatted as `AB -> C`")
+                        .with_line(i + 1),
+                )
+            }
+        }
+    }
+    Ok((polymer_template, rules))
+}
+
+pub fn polymerization(
+    polymer_template: &str,
+    rules: &HashMap<String, String>,
+    steps: usize,
+) -> Vec<char> {
+    let mut template = polymer_template.chars().collect::<Vec<char>>();
+    for step in 0..steps {
+        trace!("step {}: {} elements", step, template.len());
+        // Generate insertions, keeping the pairs without a rule apart.
+        let insertions = template
+            .windows(2)
+            .map(String::from_iter)
+            .map(|rule| rules.get(rule.as_str()).and_then(|c| c.chars().next()))
+            .collect::<Vec<Option<char>>>();
+
+        // Merge the results.
+        template = template
+            .into_iter()
+            .map(Some)
+            .interleave(insertions)
+            .flatten()
+            .collect();
+    }
+    template
+}
+
+pub fn frequency(polymer: Vec<char>) -> HashMap<char, i64> {
+    let mut letter_counts: HashMap<char, i64> = HashMap::new();
+    for c in polymer {
+        *letter_counts.entry(c).or_insert(0) += 1;
+    }
+    letter_counts
+}
+
+pub fn most_least_char(frequency_map: HashMap<char, i64>) -> (i64, i64) {
+    let most_common_element = *frequency_map.values().max().unwrap();
+    let least_common_element = *frequency_map.values().min().unwrap();
+    (most_common_element, least_common_element)
+}
+
+/// Counts the elements of the polymer without building it.
+///
+/// Each pair of the template grows independently from its neighbours, so the
+/// elements inserted between two elements in a given number of steps are
+/// cached and reused. Returns `None` if there are more elements than an `i64`
+/// can count.
+pub fn memoized_polymerization(
+    polymer_template: &str,
+    rules: &HashMap<String, String>,
+    steps: usize,
+) -> Option<HashMap<char, i64>> {
+    let template = polymer_template.chars().collect::<Vec<char>>();
+    let mut cache: HashMap<(char, char, usize), HashMap<char, i64>> = HashMap::new();
+    let mut letter_counts = frequency(template.clone());
+    for window in template.windows(2) {
+        let insertions = memoized_insertions(&mut cache, rules, window[0], window[1], steps)?;
+        add_counts(&mut letter_counts, insertions)?;
+    }
+    Some(letter_counts)
+}
+
+fn add_counts(letter_counts: &mut HashMap<char, i64>, more: HashMap<char, i64>) -> Option<()> {
+    for (c, count) in more {
+        let total = letter_counts.entry(c).or_insert(0);
+        *total = total.checked_add(count)?;
+    }
+    Some(())
+}
+
+fn memoized_insertions(
+    cache: &mut HashMap<(char, char, usize), HashMap<char, i64>>,
+    rules: &HashMap<String, String>,
+    left: char,
+    right: char,
+    steps: usize,
+) -> Option<HashMap<char, i64>> {
+    if steps == 0 {
+        return Some(HashMap::new());
+    }
+    if let Some(letter_counts) = cache.get(&(left, right, steps)) {
+        return Some(letter_counts.clone());
+    }
+
+    let pair = String::from_iter([left, right]);
+    let mut letter_counts: HashMap<char, i64> = HashMap::new();
+    if let Some(middle) = rules.get(pair.as_str()).and_then(|r| r.chars().next()) {
+        letter_counts.insert(middle, 1);
+        for (l, r) in [(left, middle), (middle, right)] {
+            let insertions = memoized_insertions(cache, rules, l, r, steps - 1)?;
+            add_counts(&mut letter_counts, insertions)?;
+        }
+    }
+    cache.insert((left, right, steps), letter_counts.clone());
+    Some(letter_counts)
+}
+
+/// Generates a template of `size` elements, and a rule for every pair of
+/// elements.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elements = "BCFHKNOPSV".chars().collect::<Vec<char>>();
+    let mut input = (0..size)
+        .map(|_| *rng.choose(&elements))
+        .collect::<String>();
+    input.push_str("\n\n");
+    for left in elements.iter() {
+        for right in elements.iter() {
+            let insertion = rng.choose(&elements);
+            input.push_str(&format!("{}{} -> {}\n", left, right, insertion));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::property::{agree, shrink_number, shrink_vec, Checker};
+
+    const RAW_INPUT: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn test_day14_parta_sample() {
+        let (polymer_template, rules) = parse_input(RAW_INPUT).unwrap();
+        let polymer = polymerization(&polymer_template, &rules, 10);
+        assert_eq!(polymer.len(), 3073);
+        let freq = frequency(polymer);
+        let (most, least) = most_least_char(freq);
+        assert_eq!(most - least, 1588);
+        // Pairs without a rule are left as they are.
+        let (polymer_template, rules) = parse_input("BA\n\nBA -> C\nBC -> D\nCA -> C").unwrap();
+        let polymer = polymerization(&polymer_template, &rules, 4);
+        assert_eq!(String::from_iter(polymer), "BDCCCCA");
+    }
+
+    #[test]
+    fn test_day14_partb_sample() {
+        let (polymer_template, rules) = parse_input(RAW_INPUT).unwrap();
+        let freq = memoized_polymerization(&polymer_template, &rules, 10).unwrap();
+        assert_eq!(freq.values().sum::<i64>(), 3073);
+        let freq = memoized_polymerization(&polymer_template, &rules, 40).unwrap();
+        let (most, least) = most_least_char(freq);
+        assert_eq!(most, 2192039569602);
+        assert_eq!(least, 3849876073);
+        assert_eq!(most - least, 2188189693529);
+        assert_eq!(memoized_polymerization(&polymer_template, &rules, 70), None);
+    }
+
+    type Polymerization = (Vec<char>, Vec<(String, String)>, usize);
+
+    /// Generates a short template of a few elements, and rules for some of
+    /// their pairs only.
+    fn generate_polymerization(rng: &mut Rng) -> Polymerization {
+        let elements = ['A', 'B', 'C', 'D'];
+        let template = (0..=rng.below(6)).map(|_| *rng.choose(&elements)).collect();
+        let mut rules = Vec::new();
+        for left in elements {
+            for right in elements {
+                if !rng.one_in(3) {
+                    let pair = String::from_iter([left, right]);
+                    rules.push((pair, rng.choose(&elements).to_string()));
+                }
+            }
+        }
+        (template, rules, rng.below(9))
+    }
+
+    fn shrink_polymerization((template, rules, steps): &Polymerization) -> Vec<Polymerization> {
+        let mut candidates = Vec::new();
+        for steps in shrink_number(*steps) {
+            candidates.push((template.clone(), rules.clone(), steps));
+        }
+        for template in shrink_vec(template) {
+            candidates.push((template, rules.clone(), *steps));
+        }
+        for rules in shrink_vec(rules) {
+            candidates.push((template.clone(), rules, *steps));
+        }
+        candidates
+    }
+
+    #[test]
+    fn test_day14_memoized_agrees_with_polymerization() {
+        Checker::new().assert(
+            generate_polymerization,
+            shrink_polymerization,
+            |(template, rules, steps)| {
+                let template = String::from_iter(template);
+                let rules = rules.iter().cloned().collect::<HashMap<String, String>>();
+                agree(
+                    Some(frequency(polymerization(&template, &rules, *steps))),
+                    memoized_polymerization(&template, &rules, *steps),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_day14_parse_errors() {
+        let error = parse_input("NNCB\n\nCH -> B\nHHN -> N").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4: expected a rule formatted as `AB -> C`: `HHN -> N`"
+        );
+        assert!(parse_input("").is_err());
+        assert!(parse_input("NNCB\n\nCH - B").is_err());
+        let template = "N".repeat((1 << 23) + 1);
+        let error = parse_countable_input(&format!("{}\n\nNN -> N", template)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the polymer template is too long to count its elements after 40 steps"
+        );
+        assert!(parse_countable_input(&template[1..]).is_ok());
+    }
+}
@@ -0,0 +1,129 @@
+//! The puzzles of Advent of Code 2021.
+
+use crate::generate::{Generator, Generators};
+use crate::repl::Probes;
+use crate::{AocError, Registry};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+pub const YEAR: u16 = 2021;
+
+/// Registers the solvers of every day.
+pub fn register(registry: &mut Registry) {
+    day01::register(registry);
+    day02::register(registry);
+    day03::register(registry);
+    day04::register(registry);
+    day05::register(registry);
+    day06::register(registry);
+    day07::register(registry);
+    day08::register(registry);
+    day09::register(registry);
+    day10::register(registry);
+    day11::register(registry);
+    day12::register(registry);
+    day13::register(registry);
+    day14::register(registry);
+}
+
+/// Registers the probes the days expose to the `repl`.
+pub fn register_probes(probes: &mut Probes) {
+    day01::register_probes(probes);
+    day04::register_probes(probes);
+    day06::register_probes(probes);
+    day11::register_probes(probes);
+    day13::register_probes(probes);
+    day14::register_probes(probes);
+}
+
+/// Registers the input generators of every day.
+pub fn register_generators(generators: &mut Generators) {
+    generators.register(Generator {
+        day: 1,
+        size: ("depths", 2000),
+        generate: day01::generate,
+    });
+    generators.register(Generator {
+        day: 2,
+        size: ("commands", 1000),
+        generate: day02::generate,
+    });
+    generators.register(Generator {
+        day: 3,
+        size: ("numbers", 1000),
+        generate: day03::generate,
+    });
+    generators.register(Generator {
+        day: 4,
+        size: ("cards", 100),
+        generate: day04::generate,
+    });
+    generators.register(Generator {
+        day: 5,
+        size: ("lines", 500),
+        generate: day05::generate,
+    });
+    generators.register(Generator {
+        day: 6,
+        size: ("lanternfish", 300),
+        generate: day06::generate,
+    });
+    generators.register(Generator {
+        day: 7,
+        size: ("crabs", 1000),
+        generate: day07::generate,
+    });
+    generators.register(Generator {
+        day: 8,
+        size: ("entries", 200),
+        generate: day08::generate,
+    });
+    generators.register(Generator {
+        day: 9,
+        size: ("side", 100),
+        generate: day09::generate,
+    });
+    generators.register(Generator {
+        day: 10,
+        size: ("lines", 100),
+        generate: day10::generate,
+    });
+    generators.register(Generator {
+        day: 11,
+        size: ("side", 10),
+        generate: day11::generate,
+    });
+    generators.register(Generator {
+        day: 12,
+        size: ("small caves", 6),
+        generate: day12::generate,
+    });
+    generators.register(Generator {
+        day: 13,
+        size: ("dots", 800),
+        generate: day13::generate,
+    });
+    generators.register(Generator {
+        day: 14,
+        size: ("elements", 20),
+        generate: day14::generate,
+    });
+}
+
+/// Reads the puzzle input of a given day of this year.
+pub fn read_asset(day: u8) -> Result<String, AocError> {
+    crate::read_asset(YEAR, day)
+}